@@ -0,0 +1,124 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter, Write};
+use std::fs;
+use std::path::Path;
+
+pub const ANSWERS_NAME: &str = "answers.toml";
+
+// only the subset of toml this store writes is understood:
+//
+// [2025.4]
+// part1 = "1493"
+// part2 = "9194"
+#[derive(Debug, Default)]
+pub struct AnswerStore {
+    answers: BTreeMap<(u32, u32, u32), String>,
+}
+
+fn parse_header(header: &str) -> Result<(u32, u32), Box<dyn Error>> {
+    let Some((year, day)) = header.split_once('.') else {
+        return Err(format!("Expected [<year>.<day>], got [{header}]").into());
+    };
+
+    Ok((year.trim().parse()?, day.trim().parse()?))
+}
+
+fn parse_entry(entry: &str) -> Result<(u32, String), Box<dyn Error>> {
+    let Some((key, value)) = entry.split_once('=') else {
+        return Err(format!("Expected part<n> = \"<answer>\", got {entry}").into());
+    };
+
+    let Some(part) = key.trim().strip_prefix("part") else {
+        return Err(format!("Unknown key: {}", key.trim()).into());
+    };
+
+    let value = value.trim();
+    let Some(answer) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) else {
+        return Err(format!("Answers must be quoted strings, got {value}").into());
+    };
+
+    Ok((part.parse()?, answer.to_string()))
+}
+
+impl AnswerStore {
+    pub fn new() -> AnswerStore {
+        AnswerStore::default()
+    }
+
+    pub fn parse(contents: &str) -> Result<AnswerStore, Box<dyn Error>> {
+        let mut store = AnswerStore::new();
+        let mut section = None;
+
+        for (index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let result = if let Some(header) = line.strip_prefix('[') {
+                match header.strip_suffix(']') {
+                    Some(header) => parse_header(header).map(|key| section = Some(key)),
+                    None => Err("Unterminated table header!".into()),
+                }
+            } else if let Some((year, day)) = section {
+                parse_entry(line).map(|(part, answer)| {
+                    store.insert(year, day, part, answer);
+                })
+            } else {
+                Err("Answer outside of a [<year>.<day>] table!".into())
+            };
+
+            if let Err(e) = result {
+                return Err(format!("{ANSWERS_NAME}:{}: {e}", index + 1).into());
+            }
+        }
+
+        Ok(store)
+    }
+
+    pub fn load(path: &Path) -> Result<AnswerStore, Box<dyn Error>> {
+        if path.is_file() {
+            AnswerStore::parse(&fs::read_to_string(path)?)
+        } else {
+            Ok(AnswerStore::new())
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, self.to_string())?;
+        Ok(())
+    }
+
+    pub fn get(&self, year: u32, day: u32, part: u32) -> Option<&str> {
+        self.answers.get(&(year, day, part)).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, year: u32, day: u32, part: u32, answer: String) -> Option<String> {
+        self.answers.insert((year, day, part), answer)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.answers.is_empty()
+    }
+}
+
+impl Display for AnswerStore {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut section = None;
+        for ((year, day, part), answer) in &self.answers {
+            if section != Some((year, day)) {
+                if section.is_some() {
+                    f.write_char('\n')?;
+                }
+
+                writeln!(f, "[{year}.{day}]")?;
+                section = Some((year, day));
+            }
+
+            writeln!(f, "part{part} = \"{answer}\"")?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::hash::{BuildHasher, RandomState};
+use std::str::FromStr;
+
+use crate::{BitMap, Neighborhood, ParseError};
+
+// bit k of `birth` or `survive` is set when a neighbor count of k applies
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rule {
+    birth: u64,
+    survive: u64,
+}
+
+// why a run stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Termination {
+    // the map stopped changing at this generation
+    Fixpoint(usize),
+    // the generation `start` came round again every `period` steps
+    Cycle { start: usize, period: usize },
+    StepLimit,
+}
+
+pub struct Automaton {
+    map: BitMap,
+    neighborhood: Neighborhood,
+    rule: Rule,
+    generation: usize,
+    changes: Vec<usize>,
+    // only the first map is kept; earlier generations are known by their hash and rebuilt from it
+    initial: BitMap,
+    hasher: RandomState,
+    seen: HashMap<u64, Vec<usize>>,
+}
+
+const fn count_mask(counts: &[usize]) -> u64 {
+    let mut mask = 0;
+    let mut i = 0;
+    while i < counts.len() {
+        mask |= 1 << counts[i];
+        i += 1;
+    }
+
+    mask
+}
+
+impl Rule {
+    // cells are born on a count in `birth`, live ones stay on a count in `survive` and die otherwise
+    pub const fn new(birth: &[usize], survive: &[usize]) -> Rule {
+        Rule {
+            birth: count_mask(birth),
+            survive: count_mask(survive),
+        }
+    }
+
+    pub const LIFE: Rule = Rule::new(&[3], &[2, 3]);
+
+    pub fn births_on(&self, count: usize) -> bool {
+        count < 64 && self.birth >> count & 1 == 1
+    }
+
+    pub fn survives_on(&self, count: usize) -> bool {
+        count < 64 && self.survive >> count & 1 == 1
+    }
+}
+
+// `counts` starts at byte `start` of the whole rule
+fn parse_counts(rule: &str, start: usize, counts: &str) -> Result<u64, ParseError> {
+    let mut mask = 0;
+    for (i, c) in counts.char_indices() {
+        let Some(count) = c.to_digit(10) else {
+            let text = &counts[i..i + c.len_utf8()];
+            return Err(ParseError::new(rule, start + i, text, "a neighbor count"));
+        };
+
+        mask |= 1 << count;
+    }
+
+    Ok(mask)
+}
+
+// "B3/S23" notation
+impl FromStr for Rule {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((birth, survive)) = s.split_once('/') else {
+            return Err(ParseError::line(1, s, "a rule like B3/S23"));
+        };
+
+        let Some(birth) = birth.strip_prefix('B') else {
+            return Err(ParseError::new(
+                s,
+                0,
+                &s[..birth.len()],
+                "'B' followed by counts",
+            ));
+        };
+
+        let survive_start = birth.len() + 2;
+        let Some(survive) = survive.strip_prefix('S') else {
+            return Err(ParseError::new(
+                s,
+                survive_start,
+                survive,
+                "'S' followed by counts",
+            ));
+        };
+
+        Ok(Rule {
+            birth: parse_counts(s, 1, birth)?,
+            survive: parse_counts(s, survive_start + 1, survive)?,
+        })
+    }
+}
+
+impl Automaton {
+    pub fn new(map: BitMap, neighborhood: Neighborhood, rule: Rule) -> Automaton {
+        let hasher = RandomState::new();
+        let seen = HashMap::from([(hasher.hash_one(&map), vec![0])]);
+        Automaton {
+            initial: map.clone(),
+            map,
+            neighborhood,
+            rule,
+            generation: 0,
+            changes: Vec::new(),
+            hasher,
+            seen,
+        }
+    }
+
+    pub fn map(&self) -> &BitMap {
+        &self.map
+    }
+
+    pub fn into_map(self) -> BitMap {
+        self.map
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    // changes[i] is the number of cells that flipped during step i + 1
+    pub fn changes(&self) -> &[usize] {
+        &self.changes
+    }
+
+    fn next_state(&self, map: &BitMap) -> BitMap {
+        let counts = map.neighbor_counts(&self.neighborhood);
+        let (columns, rows) = map.size();
+        let mut born = BitMap::new(columns, rows);
+        let mut survive = BitMap::new(columns, rows);
+
+        let max_count = self.neighborhood.offsets().len();
+        for count in 0..=max_count {
+            let (birth, survival) = (self.rule.births_on(count), self.rule.survives_on(count));
+            if !birth && !survival {
+                continue;
+            }
+
+            let matching = counts.equal_to(count);
+            for (index, word) in matching.words().iter().enumerate() {
+                if birth {
+                    born.words_mut()[index] |= word;
+                }
+                if survival {
+                    survive.words_mut()[index] |= word;
+                }
+            }
+        }
+
+        let mut next = map.clone();
+        for (index, word) in next.words_mut().iter_mut().enumerate() {
+            *word = (*word & survive.words()[index]) | (!*word & born.words()[index]);
+        }
+
+        next
+    }
+
+    // returns how many cells changed
+    pub fn step(&mut self) -> usize {
+        let next = self.next_state(&self.map);
+        let changed = self
+            .map
+            .words()
+            .iter()
+            .zip(next.words())
+            .map(|(a, b)| (a ^ b).count_ones() as usize)
+            .sum();
+
+        self.map = next;
+        self.generation += 1;
+        self.changes.push(changed);
+        changed
+    }
+
+    // steps up to `limit` times, stopping early once the map settles or repeats
+    pub fn run(&mut self, limit: usize) -> Termination {
+        for _ in 0..limit {
+            if self.step() == 0 {
+                return Termination::Fixpoint(self.generation - 1);
+            }
+
+            if let Some(start) = self.earlier_match() {
+                return Termination::Cycle {
+                    start,
+                    period: self.generation - start,
+                };
+            }
+        }
+
+        Termination::StepLimit
+    }
+
+    // a matching hash alone could be a collision, so the match is confirmed before it counts
+    fn earlier_match(&mut self) -> Option<usize> {
+        let hash = self.hasher.hash_one(&self.map);
+        let found = self
+            .seen
+            .get(&hash)
+            .and_then(|candidates| self.replay_match(candidates));
+
+        if found.is_none() {
+            self.seen.entry(hash).or_default().push(self.generation);
+        }
+
+        found
+    }
+
+    // replays from the first map up to the latest candidate generation
+    fn replay_match(&self, candidates: &[usize]) -> Option<usize> {
+        let mut state = self.initial.clone();
+        for generation in 0..=*candidates.last()? {
+            if candidates.binary_search(&generation).is_ok() && state == self.map {
+                return Some(generation);
+            }
+
+            state = self.next_state(&state);
+        }
+
+        None
+    }
+
+    pub fn run_to_fixpoint(&mut self) -> Termination {
+        self.run(usize::MAX)
+    }
+}
@@ -0,0 +1,343 @@
+use std::env;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use advent25::days::{Answers, Day, days_for, get_day};
+use advent25::{
+    ANSWERS_NAME, AnswerStore, DEFAULT_YEAR, Example, InputSource, find_examples_dir,
+    find_input_dir, read_example_in, read_manifest, validate_year,
+};
+
+const USAGE: &str = "Usage: advent [--input-dir <path>] [--year <year>] <command>
+
+Commands:
+    run <day | first..last | --all>      solve the puzzle inputs
+    run <day> <path | ->                 solve a given file, or stdin
+    check [day | first..last | --all]    compare answers for the worked examples
+    verify [day | first..last | --all]   compare answers against those recorded
+    record [day | first..last | --all] [--force]
+                                         record answers that are not yet pinned";
+
+struct Options {
+    input_dir: Option<PathBuf>,
+    year: u32,
+    positional: Vec<String>,
+}
+
+fn parse_options(args: impl Iterator<Item = String>) -> Result<Options, Box<dyn Error>> {
+    let mut options = Options {
+        input_dir: None,
+        year: DEFAULT_YEAR,
+        positional: Vec::new(),
+    };
+
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input-dir" => {
+                let Some(dir) = args.next() else {
+                    return Err("Expected a path after --input-dir!".into());
+                };
+
+                options.input_dir = Some(PathBuf::from(dir));
+            }
+            "--year" => {
+                let Some(year) = args.next() else {
+                    return Err("Expected a year after --year!".into());
+                };
+
+                options.year = year.parse()?;
+                validate_year(options.year)?;
+            }
+            _ => options.positional.push(arg),
+        }
+    }
+
+    Ok(options)
+}
+
+fn parse_day(year: u32, arg: &str) -> Result<&'static Day, Box<dyn Error>> {
+    let number: u32 = arg.parse()?;
+    match get_day(year, number) {
+        Some(day) => Ok(day),
+        None => Err(format!("Day {number} of {year} is not registered!").into()),
+    }
+}
+
+fn parse_selection(year: u32, arg: &str) -> Result<Vec<&'static Day>, Box<dyn Error>> {
+    if arg == "--all" {
+        let days: Vec<_> = days_for(year).collect();
+        return if days.is_empty() {
+            Err(format!("No days registered for {year}!").into())
+        } else {
+            Ok(days)
+        };
+    }
+
+    // ranges are inclusive, so that "1..7" runs all seven days
+    let Some((first, last)) = arg.split_once("..") else {
+        return Ok(vec![parse_day(year, arg)?]);
+    };
+
+    let last = last.strip_prefix('=').unwrap_or(last);
+    let (first, last): (u32, u32) = (first.parse()?, last.parse()?);
+    if first > last {
+        return Err("First day cannot succeed last!".into());
+    }
+
+    let days: Vec<_> = days_for(year)
+        .filter(|day| (first..=last).contains(&day.number()))
+        .collect();
+
+    if days.is_empty() {
+        Err(format!("No days of {year} registered in {first}..{last}!").into())
+    } else {
+        Ok(days)
+    }
+}
+
+struct Outcome {
+    day: u32,
+    answers: Result<Answers, Box<dyn Error>>,
+    elapsed: Duration,
+}
+
+fn run_day(day: &Day, source: &InputSource, input_dir: Option<&Path>) -> Outcome {
+    let start = Instant::now();
+    let answers = day.run(source, input_dir);
+
+    Outcome {
+        day: day.number(),
+        answers,
+        elapsed: start.elapsed(),
+    }
+}
+
+fn print_summary(outcomes: &[Outcome]) {
+    println!(
+        "{:>3} | {:<20} | {:<20} | {:>10}",
+        "Day", "Part 1", "Part 2", "Time"
+    );
+    println!("{:-<3}-+-{:-<20}-+-{:-<20}-+-{:-<10}", "", "", "", "");
+
+    for outcome in outcomes {
+        let millis = outcome.elapsed.as_secs_f64() * 1000.0;
+        match &outcome.answers {
+            Ok(answers) => println!(
+                "{:>3} | {:<20} | {:<20} | {:>8.2}ms",
+                outcome.day, answers.part1, answers.part2, millis
+            ),
+            Err(e) => println!("{:>3} | error: {e}", outcome.day),
+        }
+    }
+}
+
+fn run(options: &Options, args: &[String]) -> Result<bool, Box<dyn Error>> {
+    let outcomes: Vec<_> = match args {
+        [selection] => {
+            let days = parse_selection(options.year, selection)?;
+            let input_dir = find_input_dir(options.input_dir.as_deref())?;
+
+            days.into_iter()
+                .map(|day| run_day(day, &day.source(), Some(&input_dir)))
+                .collect()
+        }
+        [selection, source] => {
+            let [day] = parse_selection(options.year, selection)?[..] else {
+                return Err("An explicit input can only be given to a single day!".into());
+            };
+
+            let source: InputSource = source.parse()?;
+            vec![run_day(day, &source, None)]
+        }
+        _ => return Err(USAGE.into()),
+    };
+
+    print_summary(&outcomes);
+    Ok(outcomes.iter().all(|outcome| outcome.answers.is_ok()))
+}
+
+fn check_part(name: &str, part: u32, expected: Option<&str>, actual: &str) -> bool {
+    let Some(expected) = expected else {
+        return true;
+    };
+
+    if expected == actual {
+        println!("{name} part {part}: pass");
+        true
+    } else {
+        println!("{name} part {part}: FAIL");
+        println!("    - {expected}");
+        println!("    + {actual}");
+        false
+    }
+}
+
+fn check_example(day: &Day, examples_dir: &Path, example: &Example) -> bool {
+    let name = format!("{}-{}", example.day, example.index);
+    let answers = read_example_in(examples_dir, example.day, example.index)
+        .and_then(|input| day.solve(&input));
+
+    match answers {
+        Ok(answers) => {
+            let part1 = check_part(&name, 1, example.part1.as_deref(), &answers.part1);
+            let part2 = check_part(&name, 2, example.part2.as_deref(), &answers.part2);
+
+            part1 && part2
+        }
+        Err(e) => {
+            println!("{name}: error: {e}");
+            false
+        }
+    }
+}
+
+fn parse_optional_selection(
+    year: u32,
+    args: &[String],
+) -> Result<Vec<&'static Day>, Box<dyn Error>> {
+    match args {
+        [] => parse_selection(year, "--all"),
+        [selection] => parse_selection(year, selection),
+        _ => Err(USAGE.into()),
+    }
+}
+
+fn check(options: &Options, args: &[String]) -> Result<bool, Box<dyn Error>> {
+    let days = parse_optional_selection(options.year, args)?;
+
+    let input_dir = find_input_dir(options.input_dir.as_deref())?;
+    let examples_dir = find_examples_dir(&input_dir, options.year)?;
+
+    let mut checked = 0;
+    let mut passed = 0;
+    for example in read_manifest(&examples_dir)? {
+        let Some(day) = days.iter().find(|day| day.number() == example.day) else {
+            continue;
+        };
+
+        checked += 1;
+        if check_example(day, &examples_dir, &example) {
+            passed += 1;
+        }
+    }
+
+    if checked == 0 {
+        return Err("No examples found for the selected days!".into());
+    }
+
+    println!("{passed}/{checked} example(s) passed");
+    Ok(passed == checked)
+}
+
+fn verify(options: &Options, args: &[String]) -> Result<bool, Box<dyn Error>> {
+    let days = parse_optional_selection(options.year, args)?;
+
+    let input_dir = find_input_dir(options.input_dir.as_deref())?;
+    let answers_path = input_dir.join(ANSWERS_NAME);
+
+    let store = AnswerStore::load(&answers_path)?;
+    if store.is_empty() {
+        let path = answers_path.display();
+        return Err(format!("No answers recorded in {path}, run advent record first!").into());
+    }
+
+    let mut mismatches = 0;
+    for day in days {
+        let name = format!("Day {}", day.number());
+        let answers = match day.run(&day.source(), Some(&input_dir)) {
+            Ok(answers) => answers,
+            Err(e) => {
+                println!("{name}: error: {e}");
+                mismatches += 1;
+                continue;
+            }
+        };
+
+        for (part, actual) in [(1, &answers.part1), (2, &answers.part2)] {
+            match store.get(day.year(), day.number(), part) {
+                Some(expected) => {
+                    if !check_part(&name, part, Some(expected), actual) {
+                        mismatches += 1;
+                    }
+                }
+                None => println!("{name} part {part}: not recorded"),
+            }
+        }
+    }
+
+    if mismatches > 0 {
+        eprintln!(
+            "{mismatches} answer(s) no longer match {}!",
+            answers_path.display()
+        );
+    }
+
+    Ok(mismatches == 0)
+}
+
+fn record(options: &Options, args: &[String]) -> Result<bool, Box<dyn Error>> {
+    let force = args.iter().any(|arg| arg == "--force");
+    let args: Vec<_> = args
+        .iter()
+        .filter(|arg| *arg != "--force")
+        .cloned()
+        .collect();
+    let days = parse_optional_selection(options.year, &args)?;
+
+    let input_dir = find_input_dir(options.input_dir.as_deref())?;
+    let answers_path = input_dir.join(ANSWERS_NAME);
+    let mut store = AnswerStore::load(&answers_path)?;
+
+    let mut succeeded = true;
+    for day in days {
+        let name = format!("Day {}", day.number());
+        let answers = match day.run(&day.source(), Some(&input_dir)) {
+            Ok(answers) => answers,
+            Err(e) => {
+                println!("{name}: error: {e}");
+                succeeded = false;
+                continue;
+            }
+        };
+
+        for (part, actual) in [(1, answers.part1), (2, answers.part2)] {
+            match store.get(day.year(), day.number(), part) {
+                Some(recorded) if recorded == actual => {}
+                Some(recorded) if !force => {
+                    println!("{name} part {part}: keeping {recorded}, got {actual}");
+                }
+                _ => {
+                    println!("{name} part {part}: recorded {actual}");
+                    store.insert(day.year(), day.number(), part, actual);
+                }
+            }
+        }
+    }
+
+    store.save(&answers_path)?;
+    Ok(succeeded)
+}
+
+fn main() -> ExitCode {
+    let result = parse_options(env::args().skip(1)).and_then(|options| {
+        match options.positional.split_first() {
+            Some((command, remainder)) if command == "run" => run(&options, remainder),
+            Some((command, remainder)) if command == "check" => check(&options, remainder),
+            Some((command, remainder)) if command == "verify" => verify(&options, remainder),
+            Some((command, remainder)) if command == "record" => record(&options, remainder),
+            _ => Err(USAGE.into()),
+        }
+    });
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
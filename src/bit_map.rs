@@ -1,9 +1,19 @@
-use bitvector::BitVector;
+use std::hash::{Hash, Hasher};
+use std::slice;
 
+use crate::{Neighborhood, Topology};
+
+pub(crate) const WORD_BITS: usize = u64::BITS as usize;
+
+// each row starts on a fresh word, and bits past the last column are always clear
+// topology only changes how positions are addressed, so equality and hashing ignore it
+#[derive(Clone)]
 pub struct BitMap {
-    data: BitVector,
+    words: Vec<u64>,
+    stride: usize,
     columns: usize,
     rows: usize,
+    topology: Topology,
 }
 
 pub struct BitMapIter {
@@ -12,36 +22,144 @@ pub struct BitMapIter {
     columns: usize,
 }
 
+// visits set cells only, in row-major order
+pub struct EntityIter<'a> {
+    words: &'a [u64],
+    stride: usize,
+    first_row: usize,
+    next_word: usize,
+    word_index: usize,
+    current: u64,
+}
+
+// in-bounds neighbors of a cell, set or not
+pub struct NeighborIter<'a> {
+    map: &'a BitMap,
+    offsets: slice::Iter<'a, (isize, isize)>,
+    x: usize,
+    y: usize,
+}
+
+// out[x] = row[x + dx], with anything shifted in from outside the row cleared
+pub(crate) fn shift_row(row: &[u64], dx: isize, out: &mut [u64]) {
+    let word_shift = dx.unsigned_abs() / WORD_BITS;
+    let bit_shift = dx.unsigned_abs() % WORD_BITS;
+    let word_at = |i: Option<usize>| i.and_then(|i| row.get(i)).copied().unwrap_or(0);
+
+    for (i, word) in out.iter_mut().enumerate() {
+        let (near, far) = if dx >= 0 {
+            (
+                word_at(Some(i + word_shift)),
+                word_at(Some(i + word_shift + 1)),
+            )
+        } else {
+            (
+                word_at(i.checked_sub(word_shift)),
+                word_at(i.checked_sub(word_shift + 1)),
+            )
+        };
+
+        *word = match (bit_shift, dx >= 0) {
+            (0, _) => near,
+            (_, true) => (near >> bit_shift) | (far << (WORD_BITS - bit_shift)),
+            (_, false) => (near << bit_shift) | (far >> (WORD_BITS - bit_shift)),
+        };
+    }
+}
+
 impl BitMap {
     pub fn new(columns: usize, rows: usize) -> BitMap {
+        let stride = columns.div_ceil(WORD_BITS);
         BitMap {
-            data: BitVector::new(columns * rows),
+            words: vec![0; stride * rows],
+            stride,
             columns,
             rows,
+            topology: Topology::Bounded,
         }
     }
 
-    fn index_from_pos(&self, x: usize, y: usize) -> Option<usize> {
-        if x >= self.columns || y >= self.rows {
-            None
+    pub fn with_topology(mut self, topology: Topology) -> BitMap {
+        self.topology = topology;
+        self
+    }
+
+    pub fn set_topology(&mut self, topology: Topology) {
+        self.topology = topology;
+    }
+
+    pub fn topology(&self) -> Topology {
+        self.topology
+    }
+
+    // an empty map of the given size sharing this map's topology
+    pub(crate) fn derived(&self, columns: usize, rows: usize) -> BitMap {
+        BitMap::new(columns, rows).with_topology(self.topology)
+    }
+
+    fn index_from_pos(&self, x: usize, y: usize) -> Option<(usize, u64)> {
+        let (x, y) = self.topology.normalize(x, y, self.size())?;
+        Some((y * self.stride + x / WORD_BITS, 1 << (x % WORD_BITS)))
+    }
+
+    pub(crate) fn offset_pos(
+        &self,
+        x: usize,
+        y: usize,
+        delta: (isize, isize),
+    ) -> Option<(usize, usize)> {
+        self.topology.offset(x, y, delta, self.size())
+    }
+
+    pub(crate) fn stride(&self) -> usize {
+        self.stride
+    }
+
+    pub(crate) fn words(&self) -> &[u64] {
+        &self.words
+    }
+
+    pub(crate) fn words_mut(&mut self) -> &mut [u64] {
+        &mut self.words
+    }
+
+    pub(crate) fn row_words(&self, y: usize) -> &[u64] {
+        &self.words[y * self.stride..(y + 1) * self.stride]
+    }
+
+    pub(crate) fn row_words_mut(&mut self, y: usize) -> &mut [u64] {
+        &mut self.words[y * self.stride..(y + 1) * self.stride]
+    }
+
+    // valid bits of the given word within a row
+    pub(crate) fn column_mask(&self, word: usize) -> u64 {
+        let remaining = self.columns.saturating_sub(word * WORD_BITS);
+        if remaining >= WORD_BITS {
+            u64::MAX
         } else {
-            Some(x + y * self.columns)
+            (1 << remaining) - 1
         }
     }
 
     pub fn exists(&self, x: usize, y: usize) -> bool {
         self.index_from_pos(x, y)
-            .map_or(false, |i| self.data.contains(i))
+            .is_some_and(|(i, mask)| self.words[i] & mask != 0)
     }
 
     pub fn add(&mut self, x: usize, y: usize) -> bool {
-        self.index_from_pos(x, y)
-            .map_or(false, |i| self.data.insert(i))
+        self.index_from_pos(x, y).is_some_and(|(i, mask)| {
+            let inserted = self.words[i] & mask == 0;
+            self.words[i] |= mask;
+            inserted
+        })
     }
 
     pub fn remove(&mut self, x: usize, y: usize) -> bool {
-        self.index_from_pos(x, y)
-            .map_or(false, |i| self.data.remove(i))
+        self.index_from_pos(x, y).is_some_and(|(i, mask)| {
+            let removed = self.words[i] & mask != 0;
+            self.words[i] &= !mask;
+            removed
+        })
     }
 
     pub fn coordinates(&self) -> BitMapIter {
@@ -52,8 +170,60 @@ impl BitMap {
         }
     }
 
+    pub fn entities(&self) -> EntityIter<'_> {
+        EntityIter {
+            words: &self.words,
+            stride: self.stride,
+            first_row: 0,
+            next_word: 0,
+            word_index: 0,
+            current: 0,
+        }
+    }
+
+    pub fn row_iter(&self, y: usize) -> EntityIter<'_> {
+        let y = y.min(self.rows);
+        let end = (y + 1).min(self.rows);
+
+        EntityIter {
+            words: &self.words[y * self.stride..end * self.stride],
+            stride: self.stride,
+            first_row: y,
+            next_word: 0,
+            word_index: 0,
+            current: 0,
+        }
+    }
+
+    pub fn neighbors<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        neighborhood: &'a Neighborhood,
+    ) -> NeighborIter<'a> {
+        NeighborIter {
+            map: self,
+            offsets: neighborhood.offsets().iter(),
+            x,
+            y,
+        }
+    }
+
+    pub fn count_neighbors(&self, x: usize, y: usize, neighborhood: &Neighborhood) -> usize {
+        self.neighbors(x, y, neighborhood)
+            .filter(|(n_x, n_y)| self.exists(*n_x, *n_y))
+            .count()
+    }
+
+    // None if either position lies outside the map
+    pub fn distance(&self, from: (usize, usize), to: (usize, usize)) -> Option<usize> {
+        let from = self.topology.normalize(from.0, from.1, self.size())?;
+        let to = self.topology.normalize(to.0, to.1, self.size())?;
+        Some(self.topology.distance(from, to, self.size()))
+    }
+
     pub fn num_entities(&self) -> usize {
-        self.data.len()
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
     }
 
     pub fn size(&self) -> (usize, usize) {
@@ -61,6 +231,21 @@ impl BitMap {
     }
 }
 
+impl PartialEq for BitMap {
+    fn eq(&self, other: &BitMap) -> bool {
+        self.size() == other.size() && self.words == other.words
+    }
+}
+
+impl Eq for BitMap {}
+
+impl Hash for BitMap {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.size().hash(state);
+        self.words.hash(state);
+    }
+}
+
 impl Iterator for BitMapIter {
     type Item = (usize, usize);
 
@@ -77,3 +262,50 @@ impl Iterator for BitMapIter {
         }
     }
 }
+
+impl<'a> EntityIter<'a> {
+    // a single row's words, reported as row `y`
+    pub(crate) fn over_row(words: &'a [u64], y: usize) -> EntityIter<'a> {
+        EntityIter {
+            words,
+            stride: words.len().max(1),
+            first_row: y,
+            next_word: 0,
+            word_index: 0,
+            current: 0,
+        }
+    }
+}
+
+impl Iterator for EntityIter<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            self.current = *self.words.get(self.next_word)?;
+            self.word_index = self.next_word;
+            self.next_word += 1;
+        }
+
+        let bit = self.current.trailing_zeros() as usize;
+        self.current &= self.current - 1;
+
+        let x = (self.word_index % self.stride) * WORD_BITS + bit;
+        let y = self.first_row + self.word_index / self.stride;
+        Some((x, y))
+    }
+}
+
+impl Iterator for NeighborIter<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for delta in self.offsets.by_ref() {
+            if let Some(pos) = self.map.offset_pos(self.x, self.y, *delta) {
+                return Some(pos);
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,278 @@
+use std::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+
+use crate::{BitMap, EntityIter, SizeMismatch, WORD_BITS, shift_row};
+
+// a standalone row of cells; bit x is column x, so `<<` moves cells towards higher x
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitRow {
+    words: Vec<u64>,
+    columns: usize,
+}
+
+impl BitRow {
+    pub fn new(columns: usize) -> BitRow {
+        BitRow {
+            words: vec![0; columns.div_ceil(WORD_BITS)],
+            columns,
+        }
+    }
+
+    fn mask_padding(&mut self) {
+        let remaining = self.columns % WORD_BITS;
+        if remaining != 0
+            && let Some(last) = self.words.last_mut()
+        {
+            *last &= (1 << remaining) - 1;
+        }
+    }
+
+    fn check_width(&self, other: &BitRow) -> Result<(), SizeMismatch> {
+        if self.columns == other.columns {
+            Ok(())
+        } else {
+            Err(SizeMismatch {
+                expected: (self.columns, 1),
+                found: (other.columns, 1),
+            })
+        }
+    }
+
+    // padding bits stay clear as long as f maps (0, 0) to 0
+    fn combine<F>(&self, other: &BitRow, f: F) -> Result<BitRow, SizeMismatch>
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        self.check_width(other)?;
+        let words = self
+            .words
+            .iter()
+            .zip(&other.words)
+            .map(|(a, b)| f(*a, *b))
+            .collect();
+
+        Ok(BitRow {
+            words,
+            columns: self.columns,
+        })
+    }
+
+    fn shifted(&self, dx: isize) -> BitRow {
+        let mut row = BitRow::new(self.columns);
+        shift_row(&self.words, dx, &mut row.words);
+        row.mask_padding();
+        row
+    }
+
+    pub fn contains(&self, x: usize) -> bool {
+        x < self.columns && self.words[x / WORD_BITS] >> (x % WORD_BITS) & 1 == 1
+    }
+
+    pub fn insert(&mut self, x: usize) -> bool {
+        if x >= self.columns {
+            return false;
+        }
+
+        let inserted = !self.contains(x);
+        self.words[x / WORD_BITS] |= 1 << (x % WORD_BITS);
+        inserted
+    }
+
+    pub fn remove(&mut self, x: usize) -> bool {
+        let removed = self.contains(x);
+        if removed {
+            self.words[x / WORD_BITS] &= !(1 << (x % WORD_BITS));
+        }
+
+        removed
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    // set columns, left to right
+    pub fn iter(&self) -> impl Iterator<Item = usize> {
+        EntityIter::over_row(&self.words, 0).map(|(x, _)| x)
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|w| *w == 0)
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    pub fn union(&self, other: &BitRow) -> Result<BitRow, SizeMismatch> {
+        self.combine(other, |a, b| a | b)
+    }
+
+    pub fn intersection(&self, other: &BitRow) -> Result<BitRow, SizeMismatch> {
+        self.combine(other, |a, b| a & b)
+    }
+
+    pub fn difference(&self, other: &BitRow) -> Result<BitRow, SizeMismatch> {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    pub fn symmetric_difference(&self, other: &BitRow) -> Result<BitRow, SizeMismatch> {
+        self.combine(other, |a, b| a ^ b)
+    }
+}
+
+// an operator has no way to report a SizeMismatch, so rows of different widths panic
+fn expect_same_width(result: Result<BitRow, SizeMismatch>) -> BitRow {
+    result.unwrap_or_else(|mismatch| panic!("{mismatch}"))
+}
+
+impl BitMap {
+    pub fn row(&self, y: usize) -> Option<BitRow> {
+        let (columns, rows) = self.size();
+        (y < rows).then(|| BitRow {
+            words: self.row_words(y).to_vec(),
+            columns,
+        })
+    }
+
+    fn check_row(&self, y: usize, row: &BitRow) -> Result<(), SizeMismatch> {
+        let (columns, rows) = self.size();
+        if y < rows && row.columns == columns {
+            Ok(())
+        } else {
+            Err(SizeMismatch {
+                expected: (columns, rows),
+                found: (row.columns, y + 1),
+            })
+        }
+    }
+
+    pub fn set_row(&mut self, y: usize, row: &BitRow) -> Result<(), SizeMismatch> {
+        self.check_row(y, row)?;
+        self.row_words_mut(y).copy_from_slice(&row.words);
+        Ok(())
+    }
+
+    pub fn and_row(&mut self, y: usize, row: &BitRow) -> Result<(), SizeMismatch> {
+        self.check_row(y, row)?;
+        for (word, mask) in self.row_words_mut(y).iter_mut().zip(&row.words) {
+            *word &= mask;
+        }
+
+        Ok(())
+    }
+
+    pub fn or_row(&mut self, y: usize, row: &BitRow) -> Result<(), SizeMismatch> {
+        self.check_row(y, row)?;
+        for (word, mask) in self.row_words_mut(y).iter_mut().zip(&row.words) {
+            *word |= mask;
+        }
+
+        Ok(())
+    }
+}
+
+impl BitAnd for &BitRow {
+    type Output = BitRow;
+
+    fn bitand(self, other: &BitRow) -> BitRow {
+        expect_same_width(self.intersection(other))
+    }
+}
+
+impl BitOr for &BitRow {
+    type Output = BitRow;
+
+    fn bitor(self, other: &BitRow) -> BitRow {
+        expect_same_width(self.union(other))
+    }
+}
+
+impl BitXor for &BitRow {
+    type Output = BitRow;
+
+    fn bitxor(self, other: &BitRow) -> BitRow {
+        expect_same_width(self.symmetric_difference(other))
+    }
+}
+
+impl Not for &BitRow {
+    type Output = BitRow;
+
+    fn not(self) -> BitRow {
+        let mut row = BitRow {
+            words: self.words.iter().map(|w| !w).collect(),
+            columns: self.columns,
+        };
+        row.mask_padding();
+        row
+    }
+}
+
+// owned operands forward to the borrowed impls above
+macro_rules! forward_owned {
+    ($($trait:ident::$method:ident),*) => {
+        $(
+            impl $trait for BitRow {
+                type Output = BitRow;
+
+                fn $method(self, other: BitRow) -> BitRow {
+                    (&self).$method(&other)
+                }
+            }
+
+            impl $trait<&BitRow> for BitRow {
+                type Output = BitRow;
+
+                fn $method(self, other: &BitRow) -> BitRow {
+                    (&self).$method(other)
+                }
+            }
+        )*
+    };
+}
+
+forward_owned!(BitAnd::bitand, BitOr::bitor, BitXor::bitxor);
+
+impl Not for BitRow {
+    type Output = BitRow;
+
+    fn not(self) -> BitRow {
+        !&self
+    }
+}
+
+impl Shl<usize> for BitRow {
+    type Output = BitRow;
+
+    fn shl(self, n: usize) -> BitRow {
+        &self << n
+    }
+}
+
+impl Shr<usize> for BitRow {
+    type Output = BitRow;
+
+    fn shr(self, n: usize) -> BitRow {
+        &self >> n
+    }
+}
+
+impl Shl<usize> for &BitRow {
+    type Output = BitRow;
+
+    fn shl(self, n: usize) -> BitRow {
+        self.shifted(-(n.min(self.columns) as isize))
+    }
+}
+
+impl Shr<usize> for &BitRow {
+    type Output = BitRow;
+
+    fn shr(self, n: usize) -> BitRow {
+        self.shifted(n.min(self.columns) as isize)
+    }
+}
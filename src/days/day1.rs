@@ -0,0 +1,105 @@
+use std::error::Error;
+
+use crate::{Normalization, ParseError, Solution, Trailing, parse_lines};
+
+pub const POSITION_COUNT: u32 = 100;
+
+pub enum Direction {
+    Left,
+    Right,
+}
+
+pub struct Rotation {
+    pub dir: Direction,
+    pub measure: u32,
+}
+
+impl Rotation {
+    fn to_increment(&self) -> i32 {
+        let factor = match &self.dir {
+            Direction::Left => -1,
+            Direction::Right => 1,
+        };
+
+        let measure = self.measure;
+        factor * measure as i32
+    }
+}
+
+pub struct AppliedRotation {
+    pub new_pos: u32,
+    pub zero_count: u32,
+}
+
+pub fn apply_rotation(pos: u32, rotation: &Rotation) -> AppliedRotation {
+    let increment = rotation.to_increment();
+    let uncorrected = pos as i32 + increment;
+
+    let corrected = uncorrected.rem_euclid(POSITION_COUNT as i32);
+    let zero_count = (uncorrected - corrected) / POSITION_COUNT as i32;
+
+    AppliedRotation {
+        new_pos: corrected as u32,
+        zero_count: zero_count.unsigned_abs(),
+    }
+}
+
+pub fn parse_rotation(data: &str) -> Result<Rotation, ParseError> {
+    let dir = data.get(..1).unwrap_or(data);
+    let measure = &data[dir.len()..];
+
+    Ok(Rotation {
+        dir: match dir {
+            "L" => Direction::Left,
+            "R" => Direction::Right,
+            _ => return Err(ParseError::new(data, 0, dir, "a direction of L or R")),
+        },
+        measure: measure
+            .parse()
+            .map_err(|_| ParseError::new(data, dir.len(), measure, "a rotation measure"))?,
+    })
+}
+
+const STARTING_POSITION: u32 = 50;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    const NORMALIZATION: Normalization = Normalization::with_trailing(Trailing::Strip);
+
+    type Input = Vec<Rotation>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, parse_rotation)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u64, Box<dyn Error>> {
+        let mut pos = STARTING_POSITION;
+        let mut count = 0;
+
+        for rotation in input {
+            pos = apply_rotation(pos, rotation).new_pos;
+            if pos == 0 {
+                count += 1;
+            }
+        }
+
+        Ok(count)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64, Box<dyn Error>> {
+        let mut pos = STARTING_POSITION;
+        let mut count = 0;
+
+        for rotation in input {
+            let applied = apply_rotation(pos, rotation);
+            pos = applied.new_pos;
+            count += applied.zero_count as u64;
+        }
+
+        Ok(count)
+    }
+}
@@ -0,0 +1,109 @@
+use std::error::Error;
+
+use crate::{IdRange, Normalization, ParseError, Solution, Trailing, parse_lines};
+
+fn find_pattern(id: u64, factor: u64) -> Option<u64> {
+    let mut value = id % factor;
+    let mut remainder = id / factor;
+
+    loop {
+        // no more data to process
+        if remainder == 0 {
+            return Some(value);
+        }
+
+        // advance in id and get next value
+        let next = remainder % factor;
+
+        // if the next value does not equal, then there is no pattern
+        if value != next {
+            return None;
+        }
+
+        // advance
+        value = next;
+        remainder /= factor;
+    }
+}
+
+pub fn is_invalid_id(id: u64, base: u64) -> bool {
+    let mut factor = base;
+    loop {
+        if factor > id {
+            return false; // no point
+        }
+
+        if let Some(pattern) = find_pattern(id, factor) {
+            let previous_factor = factor / base;
+            if pattern >= previous_factor {
+                // no leading zero, we're good
+                return true;
+            }
+        }
+
+        factor *= base;
+    }
+}
+
+pub fn is_doubled_id(id: u64, base: u64) -> bool {
+    let mut factor = base;
+    while factor <= id / factor {
+        factor *= base;
+    }
+
+    // the upper half has no leading zero, so equal halves have none either
+    id / factor == id % factor && id / factor >= factor / base
+}
+
+pub fn sum_invalid<F: Fn(u64) -> bool>(range: &IdRange, is_invalid: F) -> u64 {
+    range.ids().filter(|id| is_invalid(*id)).sum()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    const NORMALIZATION: Normalization = Normalization::with_trailing(Trailing::Strip);
+
+    type Input = Vec<IdRange>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines = parse_lines(input, |line| {
+            let mut offset = 0;
+            let mut ranges = Vec::new();
+
+            for range in line.split(",") {
+                ranges.push(
+                    range
+                        .parse()
+                        .map_err(|e: ParseError| e.within(1, line, offset))?,
+                );
+                offset += range.len() + 1;
+            }
+
+            Ok(ranges)
+        })?;
+
+        Ok(lines.into_iter().flatten().collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<u64, Box<dyn Error>> {
+        let sum = input
+            .iter()
+            .map(|range| sum_invalid(range, |id| is_doubled_id(id, 10)))
+            .sum();
+
+        Ok(sum)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64, Box<dyn Error>> {
+        let sum = input
+            .iter()
+            .map(|range| sum_invalid(range, |id| is_invalid_id(id, 10)))
+            .sum();
+
+        Ok(sum)
+    }
+}
@@ -0,0 +1,95 @@
+use std::error::Error;
+
+use crate::{Normalization, ParseError, Solution, Trailing, parse_lines};
+
+pub fn parse_joltage(c: char) -> Option<u8> {
+    c.to_digit(10).map(|d| d as u8)
+}
+
+pub fn parse_bank(line: &str) -> Result<Vec<u8>, ParseError> {
+    line.char_indices()
+        .map(|(i, c)| {
+            parse_joltage(c)
+                .ok_or_else(|| ParseError::new(line, i, &c.to_string(), "a joltage digit"))
+        })
+        .collect()
+}
+
+struct MaxValue<'a, T> {
+    value: &'a T,
+    index: usize,
+}
+
+fn is_greater<T: Ord>(lhs: &T, rhs: &Option<MaxValue<T>>) -> bool {
+    if let Some(max) = rhs {
+        lhs > max.value
+    } else {
+        true
+    }
+}
+
+fn find_max<'a, T: Ord>(data: &'a [T]) -> Option<MaxValue<'a, T>> {
+    let mut result = None;
+    for (index, value) in data.iter().enumerate() {
+        if is_greater(value, &result) {
+            result = Some(MaxValue { value, index });
+        }
+    }
+
+    result
+}
+
+pub fn find_max_joltage(bank: &[u8], digits: usize) -> Result<u64, Box<dyn Error>> {
+    let mut joltages = bank;
+
+    let mut sum = 0;
+    for i in 0..digits {
+        // the batteries still needed for the remaining digits are held back
+        let available_batteries = (joltages.len() + i)
+            .checked_sub(digits - 1)
+            .map_or(&[][..], |num_available| &joltages[0..num_available]);
+
+        let Some(max) = find_max(available_batteries) else {
+            return Err("Not enough batteries in bank!".into());
+        };
+
+        sum *= 10;
+        sum += *max.value as u64;
+
+        joltages = &joltages[(max.index + 1)..];
+    }
+
+    Ok(sum)
+}
+
+fn total_max_joltage(banks: &[Vec<u8>], digits: usize) -> Result<u64, Box<dyn Error>> {
+    let mut sum = 0;
+    for bank in banks {
+        sum += find_max_joltage(bank, digits)?;
+    }
+
+    Ok(sum)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    const NORMALIZATION: Normalization = Normalization::with_trailing(Trailing::Strip);
+
+    type Input = Vec<Vec<u8>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, parse_bank)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u64, Box<dyn Error>> {
+        total_max_joltage(input, 2)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64, Box<dyn Error>> {
+        total_max_joltage(input, 12)
+    }
+}
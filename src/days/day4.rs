@@ -0,0 +1,53 @@
+use std::error::Error;
+
+use crate::{
+    Automaton, BitMap, MapFormat, Neighborhood, Normalization, ParseError, Rule, Solution,
+    Termination, Trailing,
+};
+
+const FORMAT: MapFormat = MapFormat::with_on("@");
+
+// a roll is forklift-accessible, and gets removed, with fewer than 4 neighboring rolls
+const RULE: Rule = Rule::new(&[], &[4, 5, 6, 7, 8]);
+
+pub fn parse_map(input: &str) -> Result<BitMap, ParseError> {
+    let map = FORMAT.parse(input)?;
+    if map.size().0 == 0 {
+        return Err(ParseError::line(
+            1,
+            input.lines().next().unwrap_or(""),
+            "a row of the map",
+        ));
+    }
+
+    Ok(map)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    const NORMALIZATION: Normalization = Normalization::with_trailing(Trailing::Strip);
+
+    type Input = BitMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_map(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize, Box<dyn Error>> {
+        let mut automaton = Automaton::new(input.clone(), Neighborhood::Moore, RULE);
+        Ok(automaton.step())
+    }
+
+    fn part2(input: &Self::Input) -> Result<usize, Box<dyn Error>> {
+        let mut automaton = Automaton::new(input.clone(), Neighborhood::Moore, RULE);
+        let Termination::Fixpoint(_) = automaton.run_to_fixpoint() else {
+            return Err("The rolls never settled!".into());
+        };
+
+        Ok(input.num_entities() - automaton.map().num_entities())
+    }
+}
@@ -0,0 +1,114 @@
+use std::cmp::Ordering;
+use std::error::Error;
+
+use crate::{IdRange, Normalization, ParseError, Solution, Trailing};
+
+fn index_of<T, F: Fn(&T) -> bool>(data: &[T], func: F) -> Option<usize> {
+    data.iter().position(func)
+}
+
+pub struct InputData {
+    pub ranges: Vec<IdRange>,
+    pub available: Vec<u64>,
+}
+
+pub fn parse_input(input: &str) -> Result<InputData, ParseError> {
+    let lines: Vec<_> = input.trim_end().lines().collect();
+
+    let Some(index) = index_of(&lines, |l| l.is_empty()) else {
+        let expected = "a blank line between ranges and IDs";
+        return Err(ParseError::line(lines.len() + 1, "", expected));
+    };
+
+    let (ranges, remainder) = lines.split_at(index);
+    let (_, ids) = remainder.split_first().unwrap();
+
+    Ok(InputData {
+        ranges: ranges
+            .iter()
+            .enumerate()
+            .map(|(i, l)| l.parse().map_err(|e: ParseError| e.within(i + 1, l, 0)))
+            .collect::<Result<Vec<_>, _>>()?,
+        available: ids
+            .iter()
+            .enumerate()
+            .map(|(i, d)| {
+                d.parse()
+                    .map_err(|_| ParseError::line(index + i + 2, d, "an ingredient ID"))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+    })
+}
+
+pub fn is_ingredient_fresh(id: u64, ranges: &[IdRange]) -> bool {
+    ranges.iter().any(|range| range.contains(id))
+}
+
+struct MergedRanges {
+    globs: Vec<IdRange>,
+    done: bool,
+}
+
+fn merge_ranges(ranges: Vec<IdRange>) -> MergedRanges {
+    let mut globs: Vec<IdRange> = Vec::new();
+
+    let initial_count = ranges.len();
+    for range in ranges {
+        if !globs.iter_mut().any(|g| g.merge(&range)) {
+            globs.push(range);
+        }
+    }
+
+    let final_count = globs.len();
+    MergedRanges {
+        globs,
+        done: match final_count.cmp(&initial_count) {
+            Ordering::Less => false,
+            Ordering::Equal => true,
+            Ordering::Greater => panic!("dude how the fuck")
+        }
+    }
+}
+
+pub fn total_possible_fresh(ranges: &[IdRange]) -> u64 {
+    let mut globs = Vec::from_iter(ranges.iter().cloned());
+    loop {
+        let merged = merge_ranges(globs);
+
+        globs = merged.globs;
+        if merged.done {
+            break;
+        }
+    }
+
+    globs.iter().map(|glob| glob.len()).sum()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    const NORMALIZATION: Normalization = Normalization::with_trailing(Trailing::Strip);
+
+    type Input = InputData;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<usize, Box<dyn Error>> {
+        let num_fresh = input
+            .available
+            .iter()
+            .filter(|id| is_ingredient_fresh(**id, &input.ranges))
+            .count();
+
+        Ok(num_fresh)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64, Box<dyn Error>> {
+        Ok(total_possible_fresh(&input.ranges))
+    }
+}
@@ -0,0 +1,266 @@
+use std::error::Error;
+
+use crate::{Normalization, ParseError, Solution, Trailing};
+
+#[derive(Debug)]
+pub enum Operation {
+    Addition,
+    Multiplication,
+}
+
+#[derive(Debug)]
+pub struct Problem {
+    pub terms: Vec<u64>,
+    pub op: Operation,
+}
+
+// whether terms are written along the rows or down the columns of a problem
+#[derive(Debug, Clone, Copy)]
+pub enum Reading {
+    Rows,
+    Columns,
+}
+
+impl Problem {
+    pub fn evaluate(&self) -> u64 {
+        let initial = match &self.op {
+            Operation::Addition => 0,
+            Operation::Multiplication => 1,
+        };
+
+        self.terms
+            .iter()
+            .fold(initial, |prev, current| match &self.op {
+                Operation::Addition => prev + current,
+                Operation::Multiplication => prev * current,
+            })
+    }
+}
+
+pub struct ProblemIterator<'a> {
+    lines: Vec<&'a str>,
+    digits: Vec<&'a str>,
+    operator_string: Option<&'a str>,
+    column: usize,
+    reading: Reading,
+}
+
+impl<'a> ProblemIterator<'a> {
+    pub fn new(input: &'a str, reading: Reading) -> Result<ProblemIterator<'a>, ParseError> {
+        let lines: Vec<_> = input.lines().collect();
+
+        if let Some((operators, tokens)) = lines.split_last() {
+            Ok(ProblemIterator {
+                digits: Vec::from(tokens),
+                operator_string: Some(operators),
+                lines,
+                column: 0,
+                reading,
+            })
+        } else {
+            Err(ParseError::line(1, "", "a row of operators"))
+        }
+    }
+
+    // offset is relative to the start of the current problem
+    fn error(&self, row: usize, offset: usize, text: &str, expected: &str) -> ParseError {
+        let line = self.lines[row];
+        ParseError::new(line, self.column + offset, text, expected).within(row + 1, line, 0)
+    }
+
+    // splits the current problem off a row of digits, always consuming it so the
+    // remaining problems stay aligned even if this one is malformed
+    fn take_block(&mut self, row: usize, count: Option<usize>) -> Result<&'a str, ParseError> {
+        let digit_line = self.digits[row];
+        let Some(count) = count else {
+            self.digits[row] = "";
+            return Ok(digit_line);
+        };
+
+        if count > digit_line.len() || !digit_line.is_char_boundary(count) {
+            self.digits[row] = "";
+
+            let expected = format!("{count} columns before the next problem");
+            return Err(self.error(row, digit_line.len(), "", &expected));
+        }
+
+        let (block, tail) = digit_line.split_at(count);
+        let mut separator = tail.chars();
+
+        match separator.next() {
+            None | Some(' ') => {
+                self.digits[row] = separator.as_str();
+                Ok(block)
+            }
+            Some(c) => {
+                self.digits[row] = separator.as_str();
+                Err(self.error(
+                    row,
+                    count,
+                    &c.to_string(),
+                    "a blank column between problems",
+                ))
+            }
+        }
+    }
+
+    fn parse_digits(&self, row: usize, block: &str) -> Result<Vec<Option<u32>>, ParseError> {
+        block
+            .char_indices()
+            .map(|(i, c)| match (c, c.to_digit(10)) {
+                (_, Some(digit)) => Ok(Some(digit)),
+                (' ', None) => Ok(None),
+                (_, None) => Err(self.error(row, i, &c.to_string(), "a digit")),
+            })
+            .collect()
+    }
+}
+
+fn parse_operation(op: char) -> Option<Operation> {
+    match op {
+        '+' => Some(Operation::Addition),
+        '*' => Some(Operation::Multiplication),
+        _ => None,
+    }
+}
+
+fn characters_until_operator(input: &str) -> Option<usize> {
+    input
+        .char_indices()
+        .find(|(_, c)| parse_operation(*c).is_some())
+        .map(|(n, _)| n)
+}
+
+fn element_at<T: Clone>(data: &[Option<T>], index: usize) -> Option<T> {
+    if index >= data.len() {
+        None
+    } else {
+        data[index].clone()
+    }
+}
+
+impl<'a> Iterator for ProblemIterator<'a> {
+    type Item = Result<Problem, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let remaining_operators = self.operator_string?;
+        let operator_row = self.lines.len() - 1;
+
+        let mut operators = remaining_operators.chars();
+        let Some(op) = operators.next() else {
+            self.operator_string = None;
+            return Some(Err(self.error(operator_row, 0, "", "an operator")));
+        };
+
+        let remainder = operators.as_str();
+        let assumed_term_count = characters_until_operator(remainder);
+
+        self.operator_string = assumed_term_count.map(|count| {
+            let (_, op_string) = remainder.split_at(count);
+            op_string
+        });
+
+        let mut max_length = 0;
+        let mut digits = Vec::new();
+        let mut first_error = None;
+
+        for row in 0..self.digits.len() {
+            let parsed = self
+                .take_block(row, assumed_term_count)
+                .and_then(|block| self.parse_digits(row, block));
+
+            match parsed {
+                Ok(line) => {
+                    max_length = line.len().max(max_length);
+                    digits.push(line);
+                }
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+
+        let parsed_op = parse_operation(op)
+            .ok_or_else(|| self.error(operator_row, 0, &op.to_string(), "an operator"));
+
+        if let Some(count) = assumed_term_count {
+            self.column += op.len_utf8() + count;
+        }
+
+        let op = match (parsed_op, first_error) {
+            (Ok(op), None) => op,
+            (Err(e), _) | (_, Some(e)) => return Some(Err(e)),
+        };
+
+        let terms = match self.reading {
+            Reading::Rows => digits
+                .iter()
+                .map(|line| {
+                    line.iter()
+                        .flatten()
+                        .fold(0, |value, digit| value * 10 + *digit as u64)
+                })
+                .collect(),
+            Reading::Columns => {
+                // padded blank columns carry no term
+                let term_count = assumed_term_count.unwrap_or(max_length);
+                (0..term_count)
+                    .filter(|term_index| {
+                        digits
+                            .iter()
+                            .any(|line| element_at(line, *term_index).is_some())
+                    })
+                    .map(|term_index| {
+                        digits.iter().fold(0, |value, line| {
+                            if let Some(digit) = element_at(line, term_index) {
+                                value * 10 + digit as u64
+                            } else {
+                                value
+                            }
+                        })
+                    })
+                    .collect()
+            }
+        };
+
+        Some(Ok(Problem { terms, op }))
+    }
+}
+
+pub fn grand_total(worksheet: &str, reading: Reading) -> Result<u64, Box<dyn Error>> {
+    let sum = ProblemIterator::new(worksheet, reading)?
+        .map(|problem| problem.map(|p| p.evaluate()))
+        .sum::<Result<u64, _>>()?;
+
+    Ok(sum)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    // rows saved without trailing spaces would otherwise misalign the last problem
+    const NORMALIZATION: Normalization = Normalization::with_trailing(Trailing::Pad);
+
+    // alignment is significant, so the worksheet is kept verbatim
+    type Input = String;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        // surface misaligned columns while the day is still known
+        for problem in ProblemIterator::new(input, Reading::Rows)? {
+            problem?;
+        }
+
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> Result<u64, Box<dyn Error>> {
+        grand_total(input, Reading::Rows)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64, Box<dyn Error>> {
+        grand_total(input, Reading::Columns)
+    }
+}
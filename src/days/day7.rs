@@ -0,0 +1,139 @@
+use std::error::Error;
+use std::mem;
+
+use crate::{BitMap, BitRow, Grid, Normalization, ParseError, RowTrim, Solution, Trailing};
+
+pub struct Manifold {
+    pub splitters: BitMap,
+    pub entrypoint: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    Entrypoint,
+    Splitter,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Tile> {
+        match c {
+            '.' => Some(Tile::Empty),
+            'S' => Some(Tile::Entrypoint),
+            '^' => Some(Tile::Splitter),
+            _ => None,
+        }
+    }
+}
+
+// the header is kept as the first, splitter-free row of the map
+pub fn parse_manifold(input: &str) -> Result<Manifold, ParseError> {
+    let grid = Grid::parse_with(
+        input,
+        RowTrim::Both,
+        "one of '.', 'S' or '^'",
+        Tile::from_char,
+    )?;
+
+    let Some(header) = grid.row(0) else {
+        return Err(ParseError::line(1, "", "a header row"));
+    };
+
+    let Some(entrypoint) = header.iter().position(|t| *t == Tile::Entrypoint) else {
+        let (index, line) = input
+            .lines()
+            .enumerate()
+            .find(|(_, line)| !line.trim().is_empty())
+            .unwrap_or_default();
+        return Err(ParseError::line(
+            index + 1,
+            line.trim(),
+            "a beam entrypoint 'S' in the header",
+        ));
+    };
+
+    Ok(Manifold {
+        splitters: grid.to_bitmap(|t| *t == Tile::Splitter),
+        entrypoint,
+    })
+}
+
+pub fn count_splits(manifold: &Manifold) -> Result<u64, Box<dyn Error>> {
+    let (width, height) = manifold.splitters.size();
+    let mut beams = BitRow::new(width);
+    beams.insert(manifold.entrypoint);
+
+    let mut split_count = 0;
+    for y in 0..height {
+        let Some(splitters) = manifold.splitters.row(y) else {
+            break;
+        };
+
+        // beams hitting a splitter continue on both sides of it, the rest carry straight on
+        let split = &beams & &splitters;
+        if split.contains(0) || split.contains(width - 1) {
+            return Err("Attempted to split off the side of the manifold!".into());
+        }
+
+        split_count += split.count_ones() as u64;
+        beams = (!&splitters & &beams) | (&split >> 1) | (&split << 1);
+    }
+
+    Ok(split_count)
+}
+
+pub fn count_timelines(manifold: &Manifold) -> Result<u64, Box<dyn Error>> {
+    let (width, height) = manifold.splitters.size();
+    let mut working = vec![0u64; width];
+
+    let mut backbuffer = working.clone();
+    backbuffer[manifold.entrypoint] = 1;
+
+    for y in 0..height {
+        // every timeline reaching a splitter continues down both sides
+        for (x, &timelines) in backbuffer.iter().enumerate() {
+            if timelines == 0 {
+                continue;
+            }
+
+            if manifold.splitters.exists(x, y) {
+                if x == 0 || x == width - 1 {
+                    return Err("Attempted to split off the side of the manifold!".into());
+                }
+
+                working[x - 1] += timelines;
+                working[x + 1] += timelines;
+            } else {
+                working[x] += timelines;
+            }
+        }
+
+        mem::swap(&mut working, &mut backbuffer);
+        working.fill(0);
+    }
+
+    Ok(backbuffer.iter().sum())
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    const NORMALIZATION: Normalization = Normalization::with_trailing(Trailing::Strip);
+
+    type Input = Manifold;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_manifold(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<u64, Box<dyn Error>> {
+        count_splits(input)
+    }
+
+    fn part2(input: &Self::Input) -> Result<u64, Box<dyn Error>> {
+        count_timelines(input)
+    }
+}
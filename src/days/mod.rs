@@ -0,0 +1,88 @@
+use std::error::Error;
+use std::path::Path;
+
+use crate::{DEFAULT_YEAR, InputSource, Solution};
+
+pub struct Answers {
+    pub part1: String,
+    pub part2: String,
+}
+
+type Solver = fn(&str) -> Result<Answers, Box<dyn Error>>;
+
+pub fn solve<S: Solution>(input: &str) -> Result<Answers, Box<dyn Error>> {
+    let input = S::NORMALIZATION.apply(input);
+    let parsed = S::parse(&input).map_err(|e| e.for_day(S::DAY))?;
+
+    Ok(Answers {
+        part1: S::part1(&parsed)?.to_string(),
+        part2: S::part2(&parsed)?.to_string(),
+    })
+}
+
+pub struct Day {
+    year: u32,
+    number: u32,
+    solver: Solver,
+}
+
+impl Day {
+    pub fn year(&self) -> u32 {
+        self.year
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn solve(&self, input: &str) -> Result<Answers, Box<dyn Error>> {
+        (self.solver)(input)
+    }
+
+    pub fn source(&self) -> InputSource {
+        InputSource::Day {
+            year: self.year,
+            day: self.number,
+        }
+    }
+
+    pub fn run(
+        &self,
+        source: &InputSource,
+        input_dir: Option<&Path>,
+    ) -> Result<Answers, Box<dyn Error>> {
+        let input = source.read_from(input_dir)?;
+        self.solve(&input)
+    }
+}
+
+// each day only needs a line here to be picked up by the runner
+macro_rules! register_days {
+    ($($module:ident::$solution:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub static DAYS: &[Day] = &[$(Day {
+            year: DEFAULT_YEAR,
+            number: <$module::$solution as Solution>::DAY,
+            solver: solve::<$module::$solution>,
+        }),*];
+    };
+}
+
+register_days! {
+    day1::Day1,
+    day2::Day2,
+    day3::Day3,
+    day4::Day4,
+    day5::Day5,
+    day6::Day6,
+    day7::Day7,
+}
+
+pub fn days_for(year: u32) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |day| day.year == year)
+}
+
+pub fn get_day(year: u32, number: u32) -> Option<&'static Day> {
+    days_for(year).find(|day| day.number == number)
+}
@@ -0,0 +1,50 @@
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+pub const MANIFEST_NAME: &str = "expected.txt";
+
+// a "?" in the manifest leaves that part unchecked
+pub struct Example {
+    pub day: u32,
+    pub index: u32,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+fn parse_expected(answer: &str) -> Option<String> {
+    if answer == "?" {
+        None
+    } else {
+        Some(answer.to_string())
+    }
+}
+
+fn parse_example(line: &str) -> Result<Example, Box<dyn Error>> {
+    let fields: Vec<_> = line.split_whitespace().collect();
+    let [name, part1, part2] = fields[..] else {
+        return Err(format!("Expected \"<day>-<n> <part 1> <part 2>\", got \"{line}\"").into());
+    };
+
+    let Some((day, index)) = name.split_once("-") else {
+        return Err(format!("Invalid example name: {name}").into());
+    };
+
+    Ok(Example {
+        day: day.parse()?,
+        index: index.parse()?,
+        part1: parse_expected(part1),
+        part2: parse_expected(part2),
+    })
+}
+
+pub fn read_manifest(examples_dir: &Path) -> Result<Vec<Example>, Box<dyn Error>> {
+    let contents = fs::read_to_string(examples_dir.join(MANIFEST_NAME))?;
+
+    contents
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(parse_example)
+        .collect()
+}
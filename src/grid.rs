@@ -0,0 +1,224 @@
+use std::fmt::{self, Display, Formatter};
+use std::mem;
+use std::str::FromStr;
+
+use crate::{BitMap, ParseError, RowTrim, column_mismatch, trimmed_rows};
+
+// cells are stored row-major, addressed by (x, y) like BitMap
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    columns: usize,
+    rows: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn filled(columns: usize, rows: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: vec![value; columns * rows],
+            columns,
+            rows,
+        }
+    }
+
+    pub fn new(columns: usize, rows: usize) -> Grid<T>
+    where
+        T: Clone + Default,
+    {
+        Grid::filled(columns, rows, T::default())
+    }
+
+    pub fn from_cells(columns: usize, rows: usize, cells: Vec<T>) -> Option<Grid<T>> {
+        (cells.len() == columns * rows).then_some(Grid {
+            cells,
+            columns,
+            rows,
+        })
+    }
+
+    // maps every character to a cell, rejecting ragged rows and characters `cell` refuses
+    pub fn parse_with<F>(
+        input: &str,
+        trim: RowTrim,
+        expected: &str,
+        cell: F,
+    ) -> Result<Grid<T>, ParseError>
+    where
+        F: Fn(char) -> Option<T>,
+    {
+        let rows = trimmed_rows(input, trim);
+        let columns = rows.first().map_or(0, |(_, _, row)| row.chars().count());
+
+        let mut cells = Vec::with_capacity(columns * rows.len());
+        for &(line_number, line, row) in &rows {
+            if row.chars().count() != columns {
+                let offset = trim.offset(line);
+                return Err(column_mismatch(line_number, line, offset, row, columns));
+            }
+
+            for (i, character) in row.char_indices() {
+                let Some(value) = cell(character) else {
+                    let text = &row[i..i + character.len_utf8()];
+                    let error = ParseError::new(row, i, text, expected);
+                    return Err(error.within(line_number, line, trim.offset(line)));
+                };
+
+                cells.push(value);
+            }
+        }
+
+        Ok(Grid {
+            cells,
+            columns,
+            rows: rows.len(),
+        })
+    }
+
+    fn index_from_pos(&self, x: usize, y: usize) -> Option<usize> {
+        if x >= self.columns || y >= self.rows {
+            None
+        } else {
+            Some(y * self.columns + x)
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.index_from_pos(x, y).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.index_from_pos(x, y).map(|i| &mut self.cells[i])
+    }
+
+    // returns the previous value, or None if the position is out of bounds
+    pub fn set(&mut self, x: usize, y: usize, value: T) -> Option<T> {
+        self.get_mut(x, y).map(|cell| mem::replace(cell, value))
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.columns, self.rows)
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.rows).then(|| &self.cells[y * self.columns..(y + 1) * self.columns])
+    }
+
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [T]> {
+        (y < self.rows).then(|| &mut self.cells[y * self.columns..(y + 1) * self.columns])
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let (start, rows) = if x < self.columns {
+            (x, self.rows)
+        } else {
+            (0, 0)
+        };
+
+        self.cells
+            .iter()
+            .skip(start)
+            .step_by(self.columns.max(1))
+            .take(rows)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.rows).filter_map(|y| self.row(y))
+    }
+
+    // ((x, y), cell) in row-major order
+    pub fn cells(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| ((i % self.columns, i / self.columns), cell))
+    }
+
+    pub fn position<P>(&self, predicate: P) -> Option<(usize, usize)>
+    where
+        P: Fn(&T) -> bool,
+    {
+        self.cells()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: Fn(&T) -> U,
+    {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            columns: self.columns,
+            rows: self.rows,
+        }
+    }
+
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let cells = (0..self.columns)
+            .flat_map(|x| self.column(x).cloned())
+            .collect();
+
+        Grid {
+            cells,
+            columns: self.rows,
+            rows: self.columns,
+        }
+    }
+
+    pub fn to_bitmap<P>(&self, predicate: P) -> BitMap
+    where
+        P: Fn(&T) -> bool,
+    {
+        let mut map = BitMap::new(self.columns, self.rows);
+        for ((x, y), cell) in self.cells() {
+            if predicate(cell) {
+                map.add(x, y);
+            }
+        }
+
+        map
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse_with(s, RowTrim::Both, "a character", Some)
+    }
+}
+
+impl From<&BitMap> for Grid<bool> {
+    fn from(map: &BitMap) -> Self {
+        let (columns, rows) = map.size();
+        let cells = map.coordinates().map(|(x, y)| map.exists(x, y)).collect();
+
+        Grid {
+            cells,
+            columns,
+            rows,
+        }
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
-use std::error::Error;
 use std::fmt::{self, Display, Formatter};
 use std::str::FromStr;
 
+use crate::ParseError;
+
 #[derive(Debug, Clone, Copy)]
 pub struct IdRange {
     first: u64,
@@ -28,6 +29,8 @@ impl IdRange {
         (self.first, self.last)
     }
 
+    // a range always holds at least one ID, so there is no is_empty to go with this
+    #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> u64 {
         self.last - self.first + 1
     }
@@ -86,18 +89,29 @@ impl Iterator for IdIterator {
 }
 
 impl FromStr for IdRange {
-    type Err = Box<dyn Error>;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Some(pos) = s.find("-") {
-            let (first_str, remainder) = s.split_at(pos);
-            let (_, last_str) = remainder.split_at(1);
-
-            let range = Self::new(first_str.parse()?, last_str.parse()?)?;
-            Ok(range)
-        } else {
-            Err("No range delimiter!".into())
-        }
+        let Some((first_str, last_str)) = s.split_once("-") else {
+            return Err(ParseError::new(
+                s,
+                0,
+                s,
+                "a range of the form <first>-<last>",
+            ));
+        };
+
+        let first = first_str
+            .parse()
+            .map_err(|_| ParseError::new(s, 0, first_str, "a first ID"))?;
+
+        let last_offset = first_str.len() + 1;
+        let last = last_str
+            .parse()
+            .map_err(|_| ParseError::new(s, last_offset, last_str, "a last ID"))?;
+
+        Self::new(first, last)
+            .map_err(|e| ParseError::new(s, 0, s, &format!("a valid range ({e})")))
     }
 }
 
@@ -1,58 +1,243 @@
+use std::convert::Infallible;
+use std::env;
 use std::error::Error;
 use std::fmt::{self, Display, Formatter};
 use std::fs::File;
-use std::io::Read;
+use std::io::{self, Read};
 use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::Normalization;
+
+pub const INPUT_DIR_VAR: &str = "ADVENT_INPUT_DIR";
+
+pub const FIRST_YEAR: u32 = 2015;
+pub const DEFAULT_YEAR: u32 = 2025;
+// no event is known past the one this crate solves
+pub const LAST_YEAR: u32 = DEFAULT_YEAR;
 
 #[derive(Debug)]
 pub enum AdventError {
     InvalidDay(u32),
-    NoInputDir(PathBuf),
-    NoInputFile(PathBuf),
+    InvalidYear(u32),
+    NoInputDir(Vec<PathBuf>),
+    NoInputFile(Vec<PathBuf>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Day { year: u32, day: u32 },
+    File(PathBuf),
+    Stdin,
 }
 
 impl Display for AdventError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             Self::InvalidDay(day) => write!(f, "Invalid day index: {day}"),
-            Self::NoInputDir(path) => write!(f, "No such input directory: {}", path.display()),
-            Self::NoInputFile(path) => write!(f, "No such input data: {}", path.display()),
+            Self::InvalidYear(year) => write!(f, "Invalid event year: {year}"),
+            Self::NoInputDir(tried) => {
+                write!(f, "No input directory found, tried:")?;
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+
+                Ok(())
+            }
+            Self::NoInputFile(tried) => {
+                write!(f, "No such input data, tried:")?;
+                for path in tried {
+                    write!(f, "\n  {}", path.display())?;
+                }
+
+                Ok(())
+            }
         }
     }
 }
 
 impl Error for AdventError {}
 
-fn get_day_input_path(day: u32) -> Result<PathBuf, AdventError> {
-    if day < 1 || day > 31 {
-        return Err(AdventError::InvalidDay(day));
+// an explicit directory or the environment variable is authoritative; only without either
+// is any input/ above the working directory searched
+pub fn find_input_dir(explicit: Option<&Path>) -> Result<PathBuf, AdventError> {
+    let configured = explicit
+        .map(Path::to_path_buf)
+        .or_else(|| env::var_os(INPUT_DIR_VAR).map(PathBuf::from));
+
+    let candidates: Vec<PathBuf> = match configured {
+        Some(dir) => vec![dir],
+        None => env::current_dir()
+            .map(|cwd| cwd.ancestors().map(|dir| dir.join("input")).collect())
+            .unwrap_or_default(),
+    };
+
+    match candidates.iter().find(|dir| dir.is_dir()) {
+        Some(dir) => Ok(dir.clone()),
+        None => Err(AdventError::NoInputDir(candidates)),
+    }
+}
+
+pub fn validate_year(year: u32) -> Result<(), AdventError> {
+    if !(FIRST_YEAR..=LAST_YEAR).contains(&year) {
+        Err(AdventError::InvalidYear(year))
+    } else {
+        Ok(())
     }
+}
 
-    let dir = Path::new("./input");
-    if !dir.is_dir() {
-        return Err(AdventError::NoInputDir(dir.to_path_buf()));
+// input/<year>/<day>.txt, falling back to the flat input/<day>.txt layout
+fn get_day_input_path(dir: &Path, year: u32, day: u32) -> Result<PathBuf, AdventError> {
+    validate_year(year)?;
+    if !(1..=31).contains(&day) {
+        return Err(AdventError::InvalidDay(day));
     }
 
-    let file = dir.join(Path::new(&format!("{day}.txt"))).to_path_buf();
+    let file_name = format!("{day}.txt");
+    let file = dir.join(year.to_string()).join(&file_name);
     if file.is_file() {
-        Ok(file)
+        return Ok(file);
+    }
+
+    let flat_file = dir.join(&file_name);
+    if flat_file.is_file() {
+        Ok(flat_file)
     } else {
-        Err(AdventError::NoInputFile(file))
+        Err(AdventError::NoInputFile(vec![file, flat_file]))
     }
 }
 
-pub fn open_input(day: u32) -> Result<File, Box<dyn Error>> {
-    let file_path = get_day_input_path(day)?;
+pub fn open_input_in(dir: &Path, year: u32, day: u32) -> Result<File, Box<dyn Error>> {
+    let file_path = get_day_input_path(dir, year, day)?;
     let file = File::open(file_path)?;
 
     Ok(file)
 }
 
-pub fn read_input(day: u32) -> Result<String, Box<dyn Error>> {
-    let mut input = open_input(day)?;
+pub fn open_input_for(year: u32, day: u32) -> Result<File, Box<dyn Error>> {
+    open_input_in(&find_input_dir(None)?, year, day)
+}
+
+pub fn open_input(day: u32) -> Result<File, Box<dyn Error>> {
+    open_input_for(DEFAULT_YEAR, day)
+}
+
+pub fn read_input_in(dir: &Path, year: u32, day: u32) -> Result<String, Box<dyn Error>> {
+    InputSource::Day { year, day }.read_from(Some(dir))
+}
+
+// examples live in <input>/<year>/examples, or <input>/examples for the flat layout
+pub fn find_examples_dir(dir: &Path, year: u32) -> Result<PathBuf, AdventError> {
+    validate_year(year)?;
+
+    let candidates = [
+        dir.join(year.to_string()).join("examples"),
+        dir.join("examples"),
+    ];
+
+    match candidates.iter().find(|dir| dir.is_dir()) {
+        Some(dir) => Ok(dir.clone()),
+        None => Err(AdventError::NoInputDir(candidates.to_vec())),
+    }
+}
+
+pub fn read_example_in(
+    examples_dir: &Path,
+    day: u32,
+    index: u32,
+) -> Result<String, Box<dyn Error>> {
+    let file = examples_dir.join(format!("{day}-{index}.txt"));
+    InputSource::File(file).read()
+}
+
+pub fn read_input_for(year: u32, day: u32) -> Result<String, Box<dyn Error>> {
+    read_input_in(&find_input_dir(None)?, year, day)
+}
+
+pub fn read_input<S: Into<InputSource>>(source: S) -> Result<String, Box<dyn Error>> {
+    source.into().read()
+}
+
+pub fn read_input_with<S: Into<InputSource>>(
+    source: S,
+    normalization: &Normalization,
+) -> Result<String, Box<dyn Error>> {
+    Ok(normalization.apply(&read_input(source)?))
+}
+
+impl InputSource {
+    // a day's input is looked up through find_input_dir, using input_dir if given
+    pub fn open_from(&self, input_dir: Option<&Path>) -> Result<Box<dyn Read>, Box<dyn Error>> {
+        match self {
+            Self::Day { year, day } => {
+                let dir = find_input_dir(input_dir)?;
+                Ok(Box::new(open_input_in(&dir, *year, *day)?))
+            }
+            Self::File(path) => {
+                if !path.is_file() {
+                    return Err(AdventError::NoInputFile(vec![path.clone()]).into());
+                }
+
+                Ok(Box::new(File::open(path)?))
+            }
+            Self::Stdin => Ok(Box::new(io::stdin())),
+        }
+    }
+
+    pub fn read_from(&self, input_dir: Option<&Path>) -> Result<String, Box<dyn Error>> {
+        let mut input = self.open_from(input_dir)?;
+
+        let mut contents = String::new();
+        input.read_to_string(&mut contents)?;
+
+        Ok(contents)
+    }
+
+    pub fn read(&self) -> Result<String, Box<dyn Error>> {
+        self.read_from(None)
+    }
+}
+
+impl From<u32> for InputSource {
+    fn from(day: u32) -> Self {
+        Self::Day {
+            year: DEFAULT_YEAR,
+            day,
+        }
+    }
+}
+
+impl From<PathBuf> for InputSource {
+    fn from(path: PathBuf) -> Self {
+        Self::File(path)
+    }
+}
+
+impl From<&Path> for InputSource {
+    fn from(path: &Path) -> Self {
+        Self::File(path.to_path_buf())
+    }
+}
 
-    let mut contents = String::new();
-    input.read_to_string(&mut contents)?;
+// "-" is stdin, anything else is a path
+impl FromStr for InputSource {
+    type Err = Infallible;
 
-    Ok(contents)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "-" {
+            Ok(Self::Stdin)
+        } else {
+            Ok(Self::File(PathBuf::from(s)))
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Day { year, day } => write!(f, "{year} day {day}"),
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+        }
+    }
 }
@@ -1,7 +1,47 @@
 mod input;
 mod id_range;
 mod bit_map;
+mod bit_row;
+mod sparse_bit_map;
+mod neighborhood;
+mod topology;
+mod neighbor_counts;
+mod set_ops;
+mod map_format;
+mod grid;
+mod transform;
+mod regions;
+mod paths;
+mod automaton;
+mod rays;
+mod summed_area;
+mod solution;
+mod example;
+mod answers;
+mod parse_error;
+mod normalize;
+
+pub mod days;
 
 pub use input::*;
 pub use id_range::*;
 pub use bit_map::*;
+pub use bit_row::*;
+pub use sparse_bit_map::*;
+pub use neighborhood::*;
+pub use topology::*;
+pub use neighbor_counts::*;
+pub use set_ops::*;
+pub use map_format::*;
+pub use grid::*;
+pub use transform::*;
+pub use regions::*;
+pub use paths::*;
+pub use automaton::*;
+pub use rays::*;
+pub use summed_area::*;
+pub use solution::*;
+pub use example::*;
+pub use answers::*;
+pub use parse_error::*;
+pub use normalize::*;
@@ -0,0 +1,180 @@
+use std::fmt::{self, Debug, Display, Formatter};
+use std::str::FromStr;
+
+use crate::{BitMap, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RowTrim {
+    Keep,
+    Start,
+    End,
+    Both,
+}
+
+// any character not listed in `on` is an empty cell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MapFormat<'a> {
+    pub on: &'a str,
+    pub trim: RowTrim,
+    // otherwise short rows are padded out with empty cells
+    pub reject_ragged: bool,
+}
+
+pub struct Glyphs<'a> {
+    map: &'a BitMap,
+    on: char,
+    off: char,
+}
+
+impl RowTrim {
+    // where the trimmed row starts within its line
+    pub(crate) fn offset(self, line: &str) -> usize {
+        match self {
+            RowTrim::Start | RowTrim::Both => line.len() - line.trim_start().len(),
+            RowTrim::Keep | RowTrim::End => 0,
+        }
+    }
+
+    fn apply(self, line: &str) -> &str {
+        match self {
+            RowTrim::Keep => line,
+            RowTrim::Start => line.trim_start(),
+            RowTrim::End => line.trim_end(),
+            RowTrim::Both => line.trim(),
+        }
+    }
+}
+
+pub(crate) fn column_mismatch(
+    line_number: usize,
+    line: &str,
+    offset: usize,
+    row: &str,
+    columns: usize,
+) -> ParseError {
+    // point at whatever runs past the expected width, or at the end of a short row
+    let column = row
+        .char_indices()
+        .nth(columns)
+        .map_or(row.len(), |(i, _)| i);
+    let expected = format!("a row of {columns} columns");
+
+    ParseError::new(row, column, &row[column..], &expected).within(line_number, line, offset)
+}
+
+// (1-based line number, line, trimmed row) for each row, leaving out blank lines at either end
+pub(crate) fn trimmed_rows(input: &str, trim: RowTrim) -> Vec<(usize, &str, &str)> {
+    let mut rows: Vec<_> = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line, trim.apply(line)))
+        .skip_while(|(_, _, row)| row.trim().is_empty())
+        .collect();
+
+    while rows.last().is_some_and(|(_, _, row)| row.trim().is_empty()) {
+        rows.pop();
+    }
+
+    rows
+}
+
+impl<'a> MapFormat<'a> {
+    pub const DEFAULT: MapFormat<'static> = MapFormat {
+        on: "#",
+        trim: RowTrim::Both,
+        reject_ragged: true,
+    };
+
+    pub const fn with_on(on: &'a str) -> MapFormat<'a> {
+        MapFormat {
+            on,
+            trim: RowTrim::Both,
+            reject_ragged: true,
+        }
+    }
+
+    pub fn parse(&self, input: &str) -> Result<BitMap, ParseError> {
+        let rows = trimmed_rows(input, self.trim);
+        let columns = if self.reject_ragged {
+            rows.first().map_or(0, |(_, _, row)| row.chars().count())
+        } else {
+            rows.iter()
+                .map(|(_, _, row)| row.chars().count())
+                .max()
+                .unwrap_or(0)
+        };
+
+        let mut map = BitMap::new(columns, rows.len());
+        for (y, (line_number, line, row)) in rows.iter().enumerate() {
+            if self.reject_ragged && row.chars().count() != columns {
+                let offset = self.trim.offset(line);
+                return Err(column_mismatch(*line_number, line, offset, row, columns));
+            }
+
+            for (x, character) in row.chars().enumerate() {
+                if self.on.contains(character) {
+                    map.add(x, y);
+                }
+            }
+        }
+
+        Ok(map)
+    }
+}
+
+impl Default for MapFormat<'_> {
+    fn default() -> Self {
+        MapFormat::DEFAULT
+    }
+}
+
+impl BitMap {
+    pub fn glyphs(&self, on: char, off: char) -> Glyphs<'_> {
+        Glyphs { map: self, on, off }
+    }
+}
+
+impl FromStr for BitMap {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        MapFormat::DEFAULT.parse(s)
+    }
+}
+
+impl Display for Glyphs<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (columns, rows) = self.map.size();
+        for y in 0..rows {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for x in 0..columns {
+                let glyph = if self.map.exists(x, y) {
+                    self.on
+                } else {
+                    self.off
+                };
+                write!(f, "{glyph}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for BitMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.glyphs('#', '.').fmt(f)
+    }
+}
+
+// the size on its own line, then the map as Display draws it
+impl Debug for BitMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (columns, rows) = self.size();
+        writeln!(f, "BitMap {columns}x{rows} ({:?})", self.topology())?;
+        Display::fmt(self, f)
+    }
+}
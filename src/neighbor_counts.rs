@@ -0,0 +1,133 @@
+use crate::{BitMap, Neighborhood, shift_row};
+
+// neighbor counts for a whole map, stored bit-sliced: plane i holds bit i of every count
+pub struct NeighborCounts {
+    planes: Vec<BitMap>,
+}
+
+impl BitMap {
+    pub fn neighbor_counts(&self, neighborhood: &Neighborhood) -> NeighborCounts {
+        let (columns, rows) = self.size();
+        let stride = self.stride();
+        let offsets = neighborhood.offsets();
+
+        let num_planes = (usize::BITS - offsets.len().leading_zeros()).max(1) as usize;
+        let mut planes = vec![self.derived(columns, rows); num_planes];
+        let mut shifted = vec![0; stride];
+
+        // wrapping or staggered rows don't shift as a whole, so count cell by cell
+        if !self.topology().is_row_uniform() {
+            for (x, y) in self.coordinates() {
+                let count = self.count_neighbors(x, y, neighborhood);
+                for (i, plane) in planes.iter_mut().enumerate() {
+                    if (count >> i) & 1 == 1 {
+                        plane.add(x, y);
+                    }
+                }
+            }
+
+            return NeighborCounts { planes };
+        }
+
+        for y in 0..rows {
+            for &(dx, dy) in offsets {
+                let Some(source) = y.checked_add_signed(dy).filter(|y_s| *y_s < rows) else {
+                    continue;
+                };
+
+                shift_row(self.row_words(source), dx, &mut shifted);
+
+                // ripple the shifted row into the counters as a one-bit addend
+                for (w, addend) in shifted.iter().enumerate() {
+                    let mut carry = addend & self.column_mask(w);
+                    for plane in planes.iter_mut() {
+                        if carry == 0 {
+                            break;
+                        }
+
+                        let word = &mut plane.row_words_mut(y)[w];
+                        let sum = *word ^ carry;
+                        carry &= *word;
+                        *word = sum;
+                    }
+                }
+            }
+        }
+
+        NeighborCounts { planes }
+    }
+
+    pub fn fewer_neighbors_than(&self, neighborhood: &Neighborhood, k: usize) -> BitMap {
+        self.neighbor_counts(neighborhood).less_than(k)
+    }
+}
+
+impl NeighborCounts {
+    pub fn get(&self, x: usize, y: usize) -> usize {
+        self.planes
+            .iter()
+            .enumerate()
+            .filter(|(_, plane)| plane.exists(x, y))
+            .map(|(i, _)| 1 << i)
+            .sum()
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        self.planes[0].size()
+    }
+
+    // (less than k, equal to k) masks, compared from the most significant plane down
+    fn compare(&self, k: usize) -> (BitMap, BitMap) {
+        let (columns, rows) = self.size();
+        let mut less = self.planes[0].derived(columns, rows);
+        let mut equal = self.planes[0].derived(columns, rows);
+
+        let stride = self.planes[0].stride();
+        let overflows = k >> self.planes.len() != 0;
+
+        for index in 0..less.words().len() {
+            let valid = self.planes[0].column_mask(index % stride.max(1));
+            let mut lt = 0;
+            let mut eq = valid;
+
+            if overflows {
+                lt = valid;
+                eq = 0;
+            } else {
+                for (i, plane) in self.planes.iter().enumerate().rev() {
+                    let bits = plane.words()[index];
+                    if (k >> i) & 1 == 1 {
+                        lt |= eq & !bits;
+                        eq &= bits;
+                    } else {
+                        eq &= !bits;
+                    }
+                }
+            }
+
+            less.words_mut()[index] = lt;
+            equal.words_mut()[index] = eq;
+        }
+
+        (less, equal)
+    }
+
+    pub fn less_than(&self, k: usize) -> BitMap {
+        self.compare(k).0
+    }
+
+    pub fn equal_to(&self, k: usize) -> BitMap {
+        self.compare(k).1
+    }
+
+    pub fn at_least(&self, k: usize) -> BitMap {
+        let (mut less, _) = self.compare(k);
+        let stride = self.planes[0].stride();
+
+        for (index, word) in less.words_mut().iter_mut().enumerate() {
+            *word = !*word & self.planes[0].column_mask(index % stride.max(1));
+        }
+
+        less
+    }
+}
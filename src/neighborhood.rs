@@ -0,0 +1,35 @@
+const VON_NEUMANN: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+const MOORE: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+// axial (dq, dr) directions around a hex
+const HEX: [(isize, isize); 6] = [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)];
+
+// offsets are (dx, dy) relative to the cell in question
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Neighborhood {
+    VonNeumann,
+    Moore,
+    Hex,
+    Custom(Vec<(isize, isize)>),
+}
+
+impl Neighborhood {
+    pub fn offsets(&self) -> &[(isize, isize)] {
+        match self {
+            Self::VonNeumann => &VON_NEUMANN,
+            Self::Moore => &MOORE,
+            Self::Hex => &HEX,
+            Self::Custom(offsets) => offsets,
+        }
+    }
+}
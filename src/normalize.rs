@@ -0,0 +1,78 @@
+use std::borrow::Cow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trailing {
+    Keep,
+    Strip,
+    // right-pads every line with spaces to the width of the longest
+    Pad,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Normalization {
+    pub strip_bom: bool,
+    pub crlf_to_lf: bool,
+    pub trailing: Trailing,
+}
+
+impl Normalization {
+    pub const NONE: Normalization = Normalization {
+        strip_bom: false,
+        crlf_to_lf: false,
+        trailing: Trailing::Keep,
+    };
+
+    pub const fn with_trailing(trailing: Trailing) -> Normalization {
+        Normalization {
+            strip_bom: true,
+            crlf_to_lf: true,
+            trailing,
+        }
+    }
+
+    pub fn apply(&self, input: &str) -> String {
+        let mut input = Cow::Borrowed(input);
+        if self.strip_bom
+            && let Some(stripped) = input.strip_prefix('\u{feff}')
+        {
+            input = Cow::Owned(stripped.to_string());
+        }
+
+        if self.crlf_to_lf && input.contains("\r\n") {
+            input = Cow::Owned(input.replace("\r\n", "\n"));
+        }
+
+        if self.trailing == Trailing::Keep {
+            return input.into_owned();
+        }
+
+        // blank lines at the end are editor artifacts rather than rows, and the final newline
+        // terminates the last line rather than starting an empty one
+        let mut body: Vec<_> = input.split('\n').collect();
+        let mut newline = "";
+        while body.last().is_some_and(|line| line.trim().is_empty()) && body.len() > 1 {
+            body.pop();
+            newline = "\n";
+        }
+
+        let width = body.iter().map(|line| line.chars().count()).max();
+        let lines: Vec<_> = body
+            .iter()
+            .map(|line| match self.trailing {
+                Trailing::Pad => {
+                    let padding = width.unwrap_or(0) - line.chars().count();
+                    format!("{line}{}", " ".repeat(padding))
+                }
+                _ => line.trim_end().to_string(),
+            })
+            .collect();
+
+        lines.join("\n") + newline
+    }
+}
+
+impl Default for Normalization {
+    fn default() -> Self {
+        Normalization::with_trailing(Trailing::Keep)
+    }
+}
@@ -0,0 +1,113 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+// columns are byte offsets into the source line, rendered as 1-based characters
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    day: Option<u32>,
+    line: usize,
+    column: usize,
+    source: String,
+    text: String,
+    expected: String,
+}
+
+impl ParseError {
+    pub fn new(source: &str, column: usize, text: &str, expected: &str) -> ParseError {
+        ParseError {
+            day: None,
+            line: 1,
+            column,
+            source: source.to_string(),
+            text: text.to_string(),
+            expected: expected.to_string(),
+        }
+    }
+
+    // error for a whole line, such as one with the wrong shape
+    pub fn line(line: usize, source: &str, expected: &str) -> ParseError {
+        ParseError::new(source, 0, source, expected).within(line, source, 0)
+    }
+
+    // relocates an error raised on a fragment into the line it was taken from
+    pub fn within(mut self, line: usize, source: &str, offset: usize) -> ParseError {
+        self.line = line;
+        self.column += offset;
+        self.source = source.to_string();
+        self
+    }
+
+    pub fn for_day(mut self, day: u32) -> ParseError {
+        self.day = Some(day);
+        self
+    }
+
+    pub fn day(&self) -> Option<u32> {
+        self.day
+    }
+
+    pub fn position(&self) -> (usize, usize) {
+        let prefix = self.source.get(..self.column).unwrap_or(&self.source);
+        (self.line, prefix.chars().count() + 1)
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    pub fn snippet(&self) -> String {
+        let (line, column) = self.position();
+        let gutter = " ".repeat(line.to_string().len());
+        let padding = " ".repeat(column - 1);
+        let carets = "^".repeat(self.text.chars().count().max(1));
+
+        format!(
+            "{gutter} |\n{line} | {}\n{gutter} | {padding}{carets}",
+            self.source
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (line, column) = self.position();
+        if let Some(day) = self.day {
+            write!(f, "day {day}, ")?;
+        }
+
+        write!(
+            f,
+            "line {line}, column {column}: expected {}",
+            self.expected
+        )?;
+        if self.text.is_empty() {
+            write!(f, ", found nothing")?;
+        } else {
+            write!(f, ", found \"{}\"", self.text)?;
+        }
+
+        write!(f, "\n{}", self.snippet())
+    }
+}
+
+impl Error for ParseError {}
+
+// parses each non-blank line after trimming it, locating any error within its line
+pub fn parse_lines<T, F>(input: &str, parse: F) -> Result<Vec<T>, ParseError>
+where
+    F: Fn(&str) -> Result<T, ParseError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let offset = line.len() - line.trim_start().len();
+            parse(line.trim()).map_err(|e| e.within(index + 1, line, offset))
+        })
+        .collect()
+}
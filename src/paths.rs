@@ -0,0 +1,156 @@
+use std::collections::VecDeque;
+
+use crate::{BitMap, Grid, Neighborhood, Topology};
+
+// which cells of the map can be walked on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Passable {
+    Set,
+    Clear,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Walk {
+    pub neighborhood: Neighborhood,
+    pub passable: Passable,
+    // steps off one edge come back in on the opposite one
+    pub wrap: bool,
+}
+
+pub struct DistanceMap {
+    distances: Grid<Option<usize>>,
+    topology: Topology,
+    parents: Grid<Option<(usize, usize)>>,
+}
+
+impl Walk {
+    pub fn new(neighborhood: Neighborhood, passable: Passable) -> Walk {
+        Walk {
+            neighborhood,
+            passable,
+            wrap: false,
+        }
+    }
+
+    pub fn wrapping(self) -> Walk {
+        Walk { wrap: true, ..self }
+    }
+
+    fn can_enter(&self, map: &BitMap, x: usize, y: usize) -> bool {
+        let (columns, rows) = map.size();
+        x < columns
+            && y < rows
+            && match self.passable {
+                Passable::Set => map.exists(x, y),
+                Passable::Clear => !map.exists(x, y),
+            }
+    }
+
+    // follows the map's own topology unless wrapping is forced
+    fn step(
+        &self,
+        map: &BitMap,
+        x: usize,
+        y: usize,
+        delta: (isize, isize),
+    ) -> Option<(usize, usize)> {
+        if self.wrap {
+            Topology::Torus.offset(x, y, delta, map.size())
+        } else {
+            map.offset_pos(x, y, delta)
+        }
+    }
+}
+
+impl BitMap {
+    // distances from the nearest of `sources`; sources that cannot be entered are ignored
+    pub fn distances<I>(&self, sources: I, walk: &Walk) -> DistanceMap
+    where
+        I: IntoIterator<Item = (usize, usize)>,
+    {
+        let (columns, rows) = self.size();
+        let mut distances = Grid::filled(columns, rows, None);
+        let mut parents = Grid::filled(columns, rows, None);
+        let mut queue = VecDeque::new();
+
+        for (x, y) in sources {
+            if walk.can_enter(self, x, y) && distances.set(x, y, Some(0)) == Some(None) {
+                queue.push_back((x, y));
+            }
+        }
+
+        while let Some((x, y)) = queue.pop_front() {
+            let distance = distances.get(x, y).copied().flatten().unwrap_or(0);
+
+            for delta in walk.neighborhood.offsets() {
+                let Some((n_x, n_y)) = walk.step(self, x, y, *delta) else {
+                    continue;
+                };
+
+                if walk.can_enter(self, n_x, n_y) && distances.get(n_x, n_y) == Some(&None) {
+                    distances.set(n_x, n_y, Some(distance + 1));
+                    parents.set(n_x, n_y, Some((x, y)));
+                    queue.push_back((n_x, n_y));
+                }
+            }
+        }
+
+        DistanceMap {
+            distances,
+            topology: self.topology(),
+            parents,
+        }
+    }
+
+    // every cell of the path, `from` and `to` included
+    pub fn shortest_path(
+        &self,
+        from: (usize, usize),
+        to: (usize, usize),
+        walk: &Walk,
+    ) -> Option<Vec<(usize, usize)>> {
+        self.distances([from], walk).path_to(to.0, to.1)
+    }
+}
+
+impl DistanceMap {
+    pub fn distance(&self, x: usize, y: usize) -> Option<usize> {
+        self.distances.get(x, y).copied().flatten()
+    }
+
+    pub fn distances(&self) -> &Grid<Option<usize>> {
+        &self.distances
+    }
+
+    pub fn reachable(&self) -> BitMap {
+        self.distances
+            .to_bitmap(Option::is_some)
+            .with_topology(self.topology)
+    }
+
+    // the farthest reachable cell, first in row-major order on ties
+    pub fn farthest(&self) -> Option<((usize, usize), usize)> {
+        self.distances
+            .cells()
+            .filter_map(|(pos, distance)| distance.map(|d| (pos, d)))
+            .fold(None, |best, (pos, d)| match best {
+                Some((_, best_d)) if best_d >= d => best,
+                _ => Some((pos, d)),
+            })
+    }
+
+    // from the nearest source to (x, y), both included
+    pub fn path_to(&self, x: usize, y: usize) -> Option<Vec<(usize, usize)>> {
+        self.distance(x, y)?;
+
+        let mut path = vec![(x, y)];
+        let mut current = (x, y);
+        while let Some(&Some(parent)) = self.parents.get(current.0, current.1) {
+            path.push(parent);
+            current = parent;
+        }
+
+        path.reverse();
+        Some(path)
+    }
+}
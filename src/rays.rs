@@ -0,0 +1,132 @@
+use crate::BitMap;
+
+// cells stepped through from a start cell, which is never included
+pub struct Ray<'a> {
+    map: &'a BitMap,
+    pos: (usize, usize),
+    step: (isize, isize),
+    // a ray round a torus gives up once it could have visited every cell
+    remaining: usize,
+}
+
+// every cell on the rasterized line, both ends included
+pub struct Line {
+    pos: (i64, i64),
+    end: (i64, i64),
+    delta: (i64, i64),
+    sign: (i64, i64),
+    error: i64,
+    done: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cast {
+    // empty cells passed through before the hit or the edge
+    pub path: Vec<(usize, usize)>,
+    pub hit: Option<(usize, usize)>,
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+pub fn line(from: (usize, usize), to: (usize, usize)) -> Line {
+    let (x0, y0) = (from.0 as i64, from.1 as i64);
+    let (x1, y1) = (to.0 as i64, to.1 as i64);
+    let delta = ((x1 - x0).abs(), -(y1 - y0).abs());
+
+    Line {
+        pos: (x0, y0),
+        end: (x1, y1),
+        delta,
+        sign: ((x1 - x0).signum(), (y1 - y0).signum()),
+        error: delta.0 + delta.1,
+        done: false,
+    }
+}
+
+impl BitMap {
+    // a slope like (4, 2) is walked as its smallest step, (2, 1)
+    pub fn ray(&self, x: usize, y: usize, slope: (isize, isize)) -> Ray<'_> {
+        let divisor = gcd(slope.0.unsigned_abs(), slope.1.unsigned_abs()).max(1) as isize;
+        let (columns, rows) = self.size();
+        let remaining = if slope == (0, 0) { 0 } else { columns * rows };
+
+        Ray {
+            map: self,
+            pos: (x, y),
+            step: (slope.0 / divisor, slope.1 / divisor),
+            remaining,
+        }
+    }
+
+    pub fn first_hit(&self, x: usize, y: usize, slope: (isize, isize)) -> Option<(usize, usize)> {
+        self.ray(x, y, slope).find(|(x, y)| self.exists(*x, *y))
+    }
+
+    pub fn cast(&self, x: usize, y: usize, slope: (isize, isize)) -> Cast {
+        let mut path = Vec::new();
+        for (x, y) in self.ray(x, y, slope) {
+            if self.exists(x, y) {
+                return Cast {
+                    path,
+                    hit: Some((x, y)),
+                };
+            }
+
+            path.push((x, y));
+        }
+
+        Cast { path, hit: None }
+    }
+
+    // the first set cell on the line, not counting `from` itself
+    pub fn first_on_line(
+        &self,
+        from: (usize, usize),
+        to: (usize, usize),
+    ) -> Option<(usize, usize)> {
+        line(from, to).skip(1).find(|(x, y)| self.exists(*x, *y))
+    }
+}
+
+impl Iterator for Ray<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        self.pos = self.map.offset_pos(self.pos.0, self.pos.1, self.step)?;
+        Some(self.pos)
+    }
+}
+
+impl Iterator for Line {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let current = self.pos;
+        if current == self.end {
+            self.done = true;
+        } else {
+            let doubled = 2 * self.error;
+            if doubled >= self.delta.1 {
+                self.error += self.delta.1;
+                self.pos.0 += self.sign.0;
+            }
+            if doubled <= self.delta.0 {
+                self.error += self.delta.0;
+                self.pos.1 += self.sign.1;
+            }
+        }
+
+        Some((current.0 as usize, current.1 as usize))
+    }
+}
@@ -0,0 +1,142 @@
+use std::collections::VecDeque;
+
+use crate::{BitMap, Grid, Neighborhood, Rect, Topology};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Component {
+    // the first cell of the component in row-major order
+    pub start: (usize, usize),
+    pub size: usize,
+    pub bounds: Rect,
+    // cell edges facing an empty cell or the outside of the map
+    pub perimeter: usize,
+}
+
+pub struct Components {
+    labels: Grid<Option<usize>>,
+    topology: Topology,
+    components: Vec<Component>,
+}
+
+impl BitMap {
+    // set cells reachable from `start` through other set cells
+    pub fn flood_fill(&self, start: (usize, usize), neighborhood: &Neighborhood) -> BitMap {
+        let (columns, rows) = self.size();
+        let mut reached = self.derived(columns, rows);
+        if !self.exists(start.0, start.1) {
+            return reached;
+        }
+
+        let mut queue = VecDeque::from([start]);
+        reached.add(start.0, start.1);
+
+        while let Some((x, y)) = queue.pop_front() {
+            for (n_x, n_y) in self.neighbors(x, y, neighborhood) {
+                if self.exists(n_x, n_y) && reached.add(n_x, n_y) {
+                    queue.push_back((n_x, n_y));
+                }
+            }
+        }
+
+        reached
+    }
+
+    pub fn components(&self, neighborhood: &Neighborhood) -> Components {
+        let (columns, rows) = self.size();
+        let mut labels = Grid::filled(columns, rows, None);
+        let mut components = Vec::new();
+
+        for start in self.entities() {
+            if labels.get(start.0, start.1).is_some_and(Option::is_some) {
+                continue;
+            }
+
+            let label = components.len();
+            let mut queue = VecDeque::from([start]);
+            labels.set(start.0, start.1, Some(label));
+
+            let mut size = 0;
+            let (mut min, mut max) = (start, start);
+
+            while let Some((x, y)) = queue.pop_front() {
+                size += 1;
+                min = (min.0.min(x), min.1.min(y));
+                max = (max.0.max(x), max.1.max(y));
+
+                for (n_x, n_y) in self.neighbors(x, y, neighborhood) {
+                    if let Some(cell) = labels.get_mut(n_x, n_y)
+                        && cell.is_none()
+                        && self.exists(n_x, n_y)
+                    {
+                        *cell = Some(label);
+                        queue.push_back((n_x, n_y));
+                    }
+                }
+            }
+
+            let bounds = Rect::new(min.0, min.1, max.0 - min.0 + 1, max.1 - min.1 + 1);
+            components.push(Component {
+                start,
+                size,
+                bounds,
+                perimeter: 0,
+            });
+        }
+
+        // a neighbor from another component still leaves an edge exposed
+        let sides = match self.topology() {
+            Topology::Hex(_) => Neighborhood::Hex,
+            Topology::Bounded | Topology::Torus => Neighborhood::VonNeumann,
+        };
+        let edges = sides.offsets().len();
+        for (x, y) in self.entities() {
+            let label = labels.get(x, y).copied().flatten();
+            let shared = self
+                .neighbors(x, y, &sides)
+                .filter(|(n_x, n_y)| labels.get(*n_x, *n_y).copied().flatten() == label)
+                .count();
+
+            if let Some(label) = label {
+                components[label].perimeter += edges - shared;
+            }
+        }
+
+        Components {
+            labels,
+            topology: self.topology(),
+            components,
+        }
+    }
+}
+
+impl Components {
+    pub fn label(&self, x: usize, y: usize) -> Option<usize> {
+        self.labels.get(x, y).copied().flatten()
+    }
+
+    pub fn labels(&self) -> &Grid<Option<usize>> {
+        &self.labels
+    }
+
+    pub fn get(&self, label: usize) -> Option<&Component> {
+        self.components.get(label)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Component> {
+        self.components.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    pub fn mask(&self, label: usize) -> BitMap {
+        self.labels
+            .to_bitmap(|l| *l == Some(label))
+            .with_topology(self.topology)
+    }
+}
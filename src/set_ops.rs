@@ -0,0 +1,126 @@
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+
+use crate::BitMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeMismatch {
+    pub expected: (usize, usize),
+    pub found: (usize, usize),
+}
+
+impl Display for SizeMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (expected_columns, expected_rows) = self.expected;
+        let (found_columns, found_rows) = self.found;
+        write!(
+            f,
+            "Map sizes differ: expected {expected_columns}x{expected_rows}, got {found_columns}x{found_rows}"
+        )
+    }
+}
+
+impl Error for SizeMismatch {}
+
+impl BitMap {
+    fn check_size(&self, other: &BitMap) -> Result<(), SizeMismatch> {
+        if self.size() == other.size() {
+            Ok(())
+        } else {
+            Err(SizeMismatch {
+                expected: self.size(),
+                found: other.size(),
+            })
+        }
+    }
+
+    // padding bits stay clear as long as f maps (0, 0) to 0
+    fn combine_with<F>(&mut self, other: &BitMap, f: F) -> Result<(), SizeMismatch>
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        self.check_size(other)?;
+        for (word, other) in self.words_mut().iter_mut().zip(other.words()) {
+            *word = f(*word, *other);
+        }
+
+        Ok(())
+    }
+
+    fn combine<F>(&self, other: &BitMap, f: F) -> Result<BitMap, SizeMismatch>
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        let mut result = self.clone();
+        result.combine_with(other, f)?;
+        Ok(result)
+    }
+
+    pub fn union_with(&mut self, other: &BitMap) -> Result<(), SizeMismatch> {
+        self.combine_with(other, |a, b| a | b)
+    }
+
+    pub fn intersect_with(&mut self, other: &BitMap) -> Result<(), SizeMismatch> {
+        self.combine_with(other, |a, b| a & b)
+    }
+
+    pub fn difference_with(&mut self, other: &BitMap) -> Result<(), SizeMismatch> {
+        self.combine_with(other, |a, b| a & !b)
+    }
+
+    pub fn symmetric_difference_with(&mut self, other: &BitMap) -> Result<(), SizeMismatch> {
+        self.combine_with(other, |a, b| a ^ b)
+    }
+
+    pub fn union(&self, other: &BitMap) -> Result<BitMap, SizeMismatch> {
+        self.combine(other, |a, b| a | b)
+    }
+
+    pub fn intersection(&self, other: &BitMap) -> Result<BitMap, SizeMismatch> {
+        self.combine(other, |a, b| a & b)
+    }
+
+    pub fn difference(&self, other: &BitMap) -> Result<BitMap, SizeMismatch> {
+        self.combine(other, |a, b| a & !b)
+    }
+
+    pub fn symmetric_difference(&self, other: &BitMap) -> Result<BitMap, SizeMismatch> {
+        self.combine(other, |a, b| a ^ b)
+    }
+
+    pub fn invert(&mut self) {
+        let stride = self.stride();
+        for index in 0..self.words().len() {
+            let mask = self.column_mask(index % stride);
+            self.words_mut()[index] ^= mask;
+        }
+    }
+
+    pub fn complement(&self) -> BitMap {
+        let mut result = self.clone();
+        result.invert();
+        result
+    }
+
+    pub fn is_subset(&self, other: &BitMap) -> Result<bool, SizeMismatch> {
+        self.check_size(other)?;
+        Ok(self
+            .words()
+            .iter()
+            .zip(other.words())
+            .all(|(a, b)| a & !b == 0))
+    }
+
+    pub fn is_disjoint(&self, other: &BitMap) -> Result<bool, SizeMismatch> {
+        self.check_size(other)?;
+        Ok(self
+            .words()
+            .iter()
+            .zip(other.words())
+            .all(|(a, b)| a & b == 0))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words().iter().all(|w| *w == 0)
+    }
+}
@@ -0,0 +1,22 @@
+use std::error::Error;
+use std::fmt::Display;
+
+use crate::{InputSource, Normalization, ParseError, read_input_with};
+
+pub trait Solution {
+    const DAY: u32;
+    const NORMALIZATION: Normalization;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<Self::Part1, Box<dyn Error>>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2, Box<dyn Error>>;
+
+    fn load<S: Into<InputSource>>(source: S) -> Result<Self::Input, Box<dyn Error>> {
+        let input = read_input_with(source, &Self::NORMALIZATION)?;
+        Ok(Self::parse(&input).map_err(|e| e.for_day(Self::DAY))?)
+    }
+}
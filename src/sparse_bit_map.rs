@@ -0,0 +1,256 @@
+use std::collections::BTreeMap;
+use std::collections::btree_map;
+use std::fmt::{self, Display, Formatter, Write};
+use std::slice;
+
+use crate::{BitMap, Neighborhood, WORD_BITS};
+
+const CHUNK_SIZE: i64 = 8;
+
+// to_bitmap gives up on bounding boxes needing more than 128 MiB of words
+const MAX_DENSE_WORDS: usize = 1 << 24;
+
+// unbounded set of cells, stored as 8x8 chunks keyed by (chunk y, chunk x); empty chunks are dropped
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SparseBitMap {
+    chunks: BTreeMap<(i64, i64), u64>,
+}
+
+// visits set cells chunk by chunk, and row-major within each chunk
+pub struct SparseEntityIter<'a> {
+    chunks: btree_map::Iter<'a, (i64, i64), u64>,
+    key: (i64, i64),
+    current: u64,
+}
+
+// neighbors past the edge of the i64 range are skipped
+pub struct SparseNeighborIter<'a> {
+    offsets: slice::Iter<'a, (isize, isize)>,
+    x: i64,
+    y: i64,
+}
+
+fn chunk_of(x: i64, y: i64) -> ((i64, i64), u64) {
+    let key = (y.div_euclid(CHUNK_SIZE), x.div_euclid(CHUNK_SIZE));
+    let bit = y.rem_euclid(CHUNK_SIZE) * CHUNK_SIZE + x.rem_euclid(CHUNK_SIZE);
+    (key, 1 << bit)
+}
+
+impl SparseBitMap {
+    pub fn new() -> SparseBitMap {
+        SparseBitMap::default()
+    }
+
+    // cell (x, y) of the dense map becomes (origin.0 + x, origin.1 + y); cells past i64 are dropped
+    pub fn from_bitmap(map: &BitMap, origin: (i64, i64)) -> SparseBitMap {
+        let mut sparse = SparseBitMap::new();
+        for (x, y) in map.entities() {
+            let x = i64::try_from(x).ok().and_then(|x| origin.0.checked_add(x));
+            let y = i64::try_from(y).ok().and_then(|y| origin.1.checked_add(y));
+            if let (Some(x), Some(y)) = (x, y) {
+                sparse.add(x, y);
+            }
+        }
+
+        sparse
+    }
+
+    pub fn exists(&self, x: i64, y: i64) -> bool {
+        let (key, mask) = chunk_of(x, y);
+        self.chunks.get(&key).is_some_and(|chunk| chunk & mask != 0)
+    }
+
+    pub fn add(&mut self, x: i64, y: i64) -> bool {
+        let (key, mask) = chunk_of(x, y);
+        let chunk = self.chunks.entry(key).or_insert(0);
+        let inserted = *chunk & mask == 0;
+        *chunk |= mask;
+        inserted
+    }
+
+    pub fn remove(&mut self, x: i64, y: i64) -> bool {
+        let (key, mask) = chunk_of(x, y);
+        let Some(chunk) = self.chunks.get_mut(&key) else {
+            return false;
+        };
+
+        let removed = *chunk & mask != 0;
+        *chunk &= !mask;
+        if *chunk == 0 {
+            self.chunks.remove(&key);
+        }
+
+        removed
+    }
+
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+    }
+
+    pub fn entities(&self) -> SparseEntityIter<'_> {
+        SparseEntityIter {
+            chunks: self.chunks.iter(),
+            key: (0, 0),
+            current: 0,
+        }
+    }
+
+    pub fn neighbors<'a>(
+        &self,
+        x: i64,
+        y: i64,
+        neighborhood: &'a Neighborhood,
+    ) -> SparseNeighborIter<'a> {
+        SparseNeighborIter {
+            offsets: neighborhood.offsets().iter(),
+            x,
+            y,
+        }
+    }
+
+    pub fn count_neighbors(&self, x: i64, y: i64, neighborhood: &Neighborhood) -> usize {
+        self.neighbors(x, y, neighborhood)
+            .filter(|(n_x, n_y)| self.exists(*n_x, *n_y))
+            .count()
+    }
+
+    pub fn num_entities(&self) -> usize {
+        self.chunks.values().map(|c| c.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chunks.is_empty()
+    }
+
+    // top-left and bottom-right set corners, both inclusive
+    fn corners(&self) -> Option<((i64, i64), (i64, i64))> {
+        let mut cells = self.entities();
+        let (x, y) = cells.next()?;
+        Some(cells.fold(((x, y), (x, y)), |(min, max), (x, y)| {
+            ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+        }))
+    }
+
+    // top-left corner and (columns, rows) of the smallest rectangle holding every set cell;
+    // an extent spanning every i64 saturates at usize::MAX
+    pub fn bounding_box(&self) -> Option<((i64, i64), (usize, usize))> {
+        let (min, max) = self.corners()?;
+        let extent = |from: i64, to: i64| (to.abs_diff(from) as usize).saturating_add(1);
+        let size = (extent(min.0, max.0), extent(min.1, max.1));
+        Some((min, size))
+    }
+
+    // cells inside the window starting at `origin`; anything outside it is dropped
+    pub fn to_bitmap_in(&self, origin: (i64, i64), columns: usize, rows: usize) -> BitMap {
+        let mut map = BitMap::new(columns, rows);
+        for (x, y) in self.entities() {
+            let x = x
+                .checked_sub(origin.0)
+                .and_then(|x| usize::try_from(x).ok());
+            let y = y
+                .checked_sub(origin.1)
+                .and_then(|y| usize::try_from(y).ok());
+            if let (Some(x), Some(y)) = (x, y) {
+                map.add(x, y);
+            }
+        }
+
+        map
+    }
+
+    // the bounding box as a dense map, along with where its top-left corner sits;
+    // None when the cells are too far apart to store densely
+    pub fn to_bitmap(&self) -> Option<((i64, i64), BitMap)> {
+        let Some((origin, (columns, rows))) = self.bounding_box() else {
+            return Some(((0, 0), BitMap::new(0, 0)));
+        };
+
+        let words = columns.div_ceil(WORD_BITS).checked_mul(rows)?;
+        (words <= MAX_DENSE_WORDS).then(|| (origin, self.to_bitmap_in(origin, columns, rows)))
+    }
+}
+
+impl From<&BitMap> for SparseBitMap {
+    fn from(map: &BitMap) -> Self {
+        SparseBitMap::from_bitmap(map, (0, 0))
+    }
+}
+
+// the bounding box, one row at a time straight from the chunks
+impl Display for SparseBitMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.corners() else {
+            return Ok(());
+        };
+
+        let chunk_columns = min.0.div_euclid(CHUNK_SIZE)..=max.0.div_euclid(CHUNK_SIZE);
+        for y in min.1..=max.1 {
+            if y > min.1 {
+                writeln!(f)?;
+            }
+
+            let chunk_y = y.div_euclid(CHUNK_SIZE);
+            let shift = y.rem_euclid(CHUNK_SIZE) * CHUNK_SIZE;
+            let chunks = (chunk_y, *chunk_columns.start())..=(chunk_y, *chunk_columns.end());
+
+            // columns written so far, counted from min.0
+            let mut written = 0;
+            for (&(_, chunk_x), chunk) in self.chunks.range(chunks) {
+                let mut row = chunk >> shift & 0xff;
+                while row != 0 {
+                    let x = chunk_x * CHUNK_SIZE + row.trailing_zeros() as i64;
+                    row &= row - 1;
+
+                    let column = x.abs_diff(min.0);
+                    for _ in written..column {
+                        f.write_char('.')?;
+                    }
+                    f.write_char('#')?;
+                    written = column.saturating_add(1);
+                }
+            }
+
+            for _ in written..=max.0.abs_diff(min.0) {
+                f.write_char('.')?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Iterator for SparseEntityIter<'_> {
+    type Item = (i64, i64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.current == 0 {
+            let (key, chunk) = self.chunks.next()?;
+            self.key = *key;
+            self.current = *chunk;
+        }
+
+        let bit = self.current.trailing_zeros() as i64;
+        self.current &= self.current - 1;
+
+        let (chunk_y, chunk_x) = self.key;
+        let x = chunk_x * CHUNK_SIZE + bit % CHUNK_SIZE;
+        let y = chunk_y * CHUNK_SIZE + bit / CHUNK_SIZE;
+        Some((x, y))
+    }
+}
+
+impl Iterator for SparseNeighborIter<'_> {
+    type Item = (i64, i64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (dx, dy) in self.offsets.by_ref() {
+            let x = self.x.checked_add(*dx as i64);
+            let y = self.y.checked_add(*dy as i64);
+            if let (Some(x), Some(y)) = (x, y) {
+                return Some((x, y));
+            }
+        }
+
+        None
+    }
+}
@@ -0,0 +1,131 @@
+use crate::{BitMap, Rect};
+
+// sums[y * (columns + 1) + x] counts the set cells above and to the left of (x, y)
+pub struct SummedArea {
+    sums: Vec<usize>,
+    columns: usize,
+    rows: usize,
+}
+
+impl BitMap {
+    pub fn summed_area(&self) -> SummedArea {
+        let (columns, rows) = self.size();
+        let width = columns + 1;
+        let mut sums = vec![0; width * (rows + 1)];
+
+        for y in 0..rows {
+            let mut row_count = 0;
+            for x in 0..columns {
+                if self.exists(x, y) {
+                    row_count += 1;
+                }
+
+                sums[(y + 1) * width + x + 1] = sums[y * width + x + 1] + row_count;
+            }
+        }
+
+        SummedArea {
+            sums,
+            columns,
+            rows,
+        }
+    }
+
+    // every all-empty rectangle that cannot be grown in any direction
+    pub fn empty_rectangles(&self) -> Vec<Rect> {
+        let (columns, rows) = self.size();
+        let area = self.summed_area();
+        let mut heights = vec![0; columns + 1];
+        let mut rectangles = Vec::new();
+
+        for y in 0..rows {
+            for (x, height) in heights.iter_mut().take(columns).enumerate() {
+                *height = if self.exists(x, y) { 0 } else { *height + 1 };
+            }
+
+            // (first column, height) of runs no lower than their height, lowest at the bottom
+            let mut stack: Vec<(usize, usize)> = Vec::new();
+            for (x, &height) in heights.iter().enumerate() {
+                let mut start = x;
+                while let Some(&(run_start, run_height)) = stack.last() {
+                    if run_height <= height {
+                        break;
+                    }
+
+                    stack.pop();
+                    start = run_start;
+
+                    // already blocked on the left, right and top; the row below must block it too
+                    let rect = Rect::new(run_start, y + 1 - run_height, x - run_start, run_height);
+                    let below = Rect::new(run_start, y + 1, x - run_start, 1);
+                    if y + 1 == rows || area.count(below) > 0 {
+                        rectangles.push(rect);
+                    }
+                }
+
+                if height > 0 && stack.last().is_none_or(|(_, top)| *top < height) {
+                    stack.push((start, height));
+                }
+            }
+        }
+
+        rectangles
+    }
+}
+
+impl SummedArea {
+    fn sum_at(&self, x: usize, y: usize) -> usize {
+        self.sums[y * (self.columns + 1) + x]
+    }
+
+    pub fn size(&self) -> (usize, usize) {
+        (self.columns, self.rows)
+    }
+
+    // set cells inside `rect`, ignoring any part of it outside the map
+    pub fn count(&self, rect: Rect) -> usize {
+        let x0 = rect.x.min(self.columns);
+        let y0 = rect.y.min(self.rows);
+        let x1 = rect.x.saturating_add(rect.columns).min(self.columns);
+        let y1 = rect.y.saturating_add(rect.rows).min(self.rows);
+
+        self.sum_at(x1, y1) + self.sum_at(x0, y0) - self.sum_at(x0, y1) - self.sum_at(x1, y0)
+    }
+
+    pub fn total(&self) -> usize {
+        self.sum_at(self.columns, self.rows)
+    }
+
+    fn windows(&self, columns: usize, rows: usize) -> impl Iterator<Item = (Rect, usize)> {
+        let fits = columns <= self.columns && rows <= self.rows;
+        let (across, down) = if fits {
+            (self.columns - columns + 1, self.rows - rows + 1)
+        } else {
+            (0, 0)
+        };
+
+        (0..down).flat_map(move |y| {
+            (0..across).map(move |x| {
+                let rect = Rect::new(x, y, columns, rows);
+                (rect, self.count(rect))
+            })
+        })
+    }
+
+    // the window of the given size holding the most set cells, first in row-major order on ties
+    pub fn densest(&self, columns: usize, rows: usize) -> Option<(Rect, usize)> {
+        self.windows(columns, rows)
+            .fold(None, |best, (rect, count)| match best {
+                Some((_, best_count)) if best_count >= count => best,
+                _ => Some((rect, count)),
+            })
+    }
+
+    pub fn emptiest(&self, columns: usize, rows: usize) -> Option<(Rect, usize)> {
+        self.windows(columns, rows)
+            .fold(None, |best, (rect, count)| match best {
+                Some((_, best_count)) if best_count <= count => best,
+                _ => Some((rect, count)),
+            })
+    }
+}
@@ -0,0 +1,110 @@
+// how (x, y) positions of a map relate to each other
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Topology {
+    #[default]
+    Bounded,
+    // both axes wrap around
+    Torus,
+    Hex(HexLayout),
+}
+
+// pointy-topped hexes stored in rows; neighborhood offsets are read as axial (dq, dr)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexLayout {
+    // x is q and y is r
+    Axial,
+    // odd rows are shoved half a hex to the right
+    OddRows,
+    // even rows are shoved half a hex to the right
+    EvenRows,
+}
+
+impl HexLayout {
+    fn offset_to_axial(self, x: i64, y: i64) -> (i64, i64) {
+        match self {
+            HexLayout::Axial => (x, y),
+            HexLayout::OddRows => (x - (y - y.rem_euclid(2)) / 2, y),
+            HexLayout::EvenRows => (x - (y + y.rem_euclid(2)) / 2, y),
+        }
+    }
+
+    fn axial_to_offset(self, q: i64, r: i64) -> (i64, i64) {
+        match self {
+            HexLayout::Axial => (q, r),
+            HexLayout::OddRows => (q + (r - r.rem_euclid(2)) / 2, r),
+            HexLayout::EvenRows => (q + (r + r.rem_euclid(2)) / 2, r),
+        }
+    }
+}
+
+impl Topology {
+    // the in-map position (x, y) refers to, if any
+    pub(crate) fn normalize(
+        self,
+        x: usize,
+        y: usize,
+        size: (usize, usize),
+    ) -> Option<(usize, usize)> {
+        let (columns, rows) = size;
+        match self {
+            Topology::Torus if columns > 0 && rows > 0 => Some((x % columns, y % rows)),
+            _ if x < columns && y < rows => Some((x, y)),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn offset(
+        self,
+        x: usize,
+        y: usize,
+        delta: (isize, isize),
+        size: (usize, usize),
+    ) -> Option<(usize, usize)> {
+        let (columns, rows) = size;
+        let (dx, dy) = (delta.0 as i64, delta.1 as i64);
+
+        let (x, y) = match self {
+            Topology::Bounded => (x as i64 + dx, y as i64 + dy),
+            Topology::Torus => (
+                (x as i64 + dx).rem_euclid(columns.max(1) as i64),
+                (y as i64 + dy).rem_euclid(rows.max(1) as i64),
+            ),
+            Topology::Hex(layout) => {
+                let (q, r) = layout.offset_to_axial(x as i64, y as i64);
+                layout.axial_to_offset(q + dx, r + dy)
+            }
+        };
+
+        let x = usize::try_from(x).ok()?;
+        let y = usize::try_from(y).ok()?;
+        self.normalize(x, y, size)
+    }
+
+    // fewest steps between two positions: orthogonal steps on rectangles, hex steps on hexes
+    pub(crate) fn distance(
+        self,
+        from: (usize, usize),
+        to: (usize, usize),
+        size: (usize, usize),
+    ) -> usize {
+        let (columns, rows) = size;
+        let dx = from.0.abs_diff(to.0);
+        let dy = from.1.abs_diff(to.1);
+
+        match self {
+            Topology::Bounded => dx + dy,
+            Topology::Torus => dx.min(columns - dx) + dy.min(rows - dy),
+            Topology::Hex(layout) => {
+                let (q1, r1) = layout.offset_to_axial(from.0 as i64, from.1 as i64);
+                let (q2, r2) = layout.offset_to_axial(to.0 as i64, to.1 as i64);
+                let (dq, dr) = (q1 - q2, r1 - r2);
+                ((dq.abs() + dr.abs() + (dq + dr).abs()) / 2) as usize
+            }
+        }
+    }
+
+    // whether a whole row can be shifted by the same dx for every neighbor offset
+    pub(crate) fn is_row_uniform(self) -> bool {
+        matches!(self, Topology::Bounded | Topology::Hex(HexLayout::Axial))
+    }
+}
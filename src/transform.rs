@@ -0,0 +1,149 @@
+use crate::{BitMap, WORD_BITS, shift_row};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub columns: usize,
+    pub rows: usize,
+}
+
+impl Rect {
+    pub fn new(x: usize, y: usize, columns: usize, rows: usize) -> Rect {
+        Rect {
+            x,
+            y,
+            columns,
+            rows,
+        }
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        (self.x..self.x + self.columns).contains(&x) && (self.y..self.y + self.rows).contains(&y)
+    }
+
+    pub fn area(&self) -> usize {
+        self.columns * self.rows
+    }
+}
+
+impl BitMap {
+    fn remap<F>(&self, columns: usize, rows: usize, f: F) -> BitMap
+    where
+        F: Fn(usize, usize) -> (usize, usize),
+    {
+        let mut map = self.derived(columns, rows);
+        for (x, y) in self.entities() {
+            let (x, y) = f(x, y);
+            map.add(x, y);
+        }
+
+        map
+    }
+
+    pub fn transpose(&self) -> BitMap {
+        let (columns, rows) = self.size();
+        self.remap(rows, columns, |x, y| (y, x))
+    }
+
+    // rotations are clockwise
+    pub fn rotate_90(&self) -> BitMap {
+        let (columns, rows) = self.size();
+        self.remap(rows, columns, |x, y| (rows - 1 - y, x))
+    }
+
+    pub fn rotate_180(&self) -> BitMap {
+        let (columns, rows) = self.size();
+        self.remap(columns, rows, |x, y| (columns - 1 - x, rows - 1 - y))
+    }
+
+    pub fn rotate_270(&self) -> BitMap {
+        let (columns, rows) = self.size();
+        self.remap(rows, columns, |x, y| (y, columns - 1 - x))
+    }
+
+    // mirrors left to right
+    pub fn flip_horizontal(&self) -> BitMap {
+        let (columns, rows) = self.size();
+        self.remap(columns, rows, |x, y| (columns - 1 - x, y))
+    }
+
+    // mirrors top to bottom
+    pub fn flip_vertical(&self) -> BitMap {
+        let (columns, rows) = self.size();
+        let mut map = self.derived(columns, rows);
+        for y in 0..rows {
+            map.row_words_mut(rows - 1 - y)
+                .copy_from_slice(self.row_words(y));
+        }
+
+        map
+    }
+
+    // the part of `rect` that lies outside the map is dropped
+    pub fn crop(&self, rect: Rect) -> BitMap {
+        let (columns, rows) = self.size();
+        let x = rect.x.min(columns);
+        let y = rect.y.min(rows);
+        let mut map = self.derived(rect.columns.min(columns - x), rect.rows.min(rows - y));
+
+        for row in 0..map.size().1 {
+            shift_row(self.row_words(y + row), x as isize, map.row_words_mut(row));
+            for w in 0..map.stride() {
+                let mask = map.column_mask(w);
+                map.row_words_mut(row)[w] &= mask;
+            }
+        }
+
+        map
+    }
+
+    pub fn grow(&self, left: usize, top: usize, right: usize, bottom: usize) -> BitMap {
+        let (columns, rows) = self.size();
+        let mut map = self.derived(left + columns + right, top + rows + bottom);
+
+        for y in 0..rows {
+            shift_row(
+                self.row_words(y),
+                -(left as isize),
+                map.row_words_mut(top + y),
+            );
+        }
+
+        map
+    }
+
+    pub fn pad(&self, border: usize) -> BitMap {
+        self.grow(border, border, border, border)
+    }
+
+    pub fn bounding_box(&self) -> Option<Rect> {
+        let (_, rows) = self.size();
+        let occupied = |y: &usize| self.row_words(*y).iter().any(|w| *w != 0);
+        let top = (0..rows).find(occupied)?;
+        let bottom = (0..rows).rfind(occupied)?;
+
+        // every column that is set in any row
+        let mut union = vec![0; self.stride()];
+        for y in top..=bottom {
+            for (acc, word) in union.iter_mut().zip(self.row_words(y)) {
+                *acc |= word;
+            }
+        }
+
+        let first = union.iter().position(|w| *w != 0)?;
+        let last = union.iter().rposition(|w| *w != 0)?;
+        let left = first * WORD_BITS + union[first].trailing_zeros() as usize;
+        let right = last * WORD_BITS + WORD_BITS - 1 - union[last].leading_zeros() as usize;
+
+        Some(Rect::new(left, top, right - left + 1, bottom - top + 1))
+    }
+
+    // shrinks to the bounding box, or to an empty map if nothing is set
+    pub fn trim(&self) -> BitMap {
+        match self.bounding_box() {
+            Some(rect) => self.crop(rect),
+            None => self.derived(0, 0),
+        }
+    }
+}